@@ -0,0 +1,106 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Simulated time since power-on.
+///
+/// Shared by the driver's `Sleep`, the controllers and the window.
+/// By default, time only moves when the driver sleeps or toggles E.
+/// With a time scale, it also follows the wall clock, and moving it
+/// forward waits for the wall clock to catch up.
+#[derive(Clone, Default)]
+pub struct Clock {
+    state: Arc<Mutex<ClockState>>,
+}
+
+#[derive(Default)]
+struct ClockState {
+    elapsed: Duration,
+    pacing: Option<Pacing>,
+}
+
+struct Pacing {
+    scale: f64,
+    // Simulated time and wall time when pacing started
+    elapsed: Duration,
+    instant: Instant,
+}
+
+impl Pacing {
+    /// Simulated time reached by the wall clock.
+    fn now(&self) -> Duration {
+        self.elapsed + scale(self.instant.elapsed(), self.scale)
+    }
+}
+
+impl ClockState {
+    fn now(&self) -> Duration {
+        match self.pacing {
+            Some(ref pacing) => ::std::cmp::max(self.elapsed, pacing.now()),
+            None => self.elapsed,
+        }
+    }
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Clock::default()
+    }
+
+    /// Returns the simulated time elapsed since power-on.
+    pub fn now(&self) -> Duration {
+        self.state.lock().unwrap().now()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let wait = {
+            let mut state = self.state.lock().unwrap();
+            state.elapsed = state.now() + duration;
+            match state.pacing {
+                Some(ref pacing) => {
+                    let ahead = state.elapsed - ::std::cmp::min(state.elapsed, pacing.now());
+                    scale(ahead, 1.0 / pacing.scale)
+                }
+                None => Duration::from_secs(0),
+            }
+        };
+        // Don't keep the window waiting
+        if wait > Duration::from_secs(0) {
+            thread::sleep(wait);
+        }
+    }
+
+    /// Paces simulated time against the wall clock.
+    ///
+    /// `1.0` runs at hardware speed, `0.01` a hundred times slower.
+    /// `None` goes back to a purely virtual clock.
+    pub fn set_time_scale(&self, time_scale: Option<f64>) {
+        let mut state = self.state.lock().unwrap();
+        let elapsed = state.now();
+        state.elapsed = elapsed;
+        state.pacing = time_scale.map(|scale| {
+            assert!(scale > 0.0, "time scale must be positive");
+            Pacing {
+                scale: scale,
+                elapsed: elapsed,
+                instant: Instant::now(),
+            }
+        });
+    }
+}
+
+/// Returns a duration in whole microseconds.
+pub fn micros(duration: Duration) -> u64 {
+    duration.as_secs() * 1_000_000 + duration.subsec_nanos() as u64 / 1000
+}
+
+/// Returns a duration in nanoseconds.
+pub fn nanos(duration: Duration) -> u64 {
+    duration.as_secs() * 1_000_000_000 + duration.subsec_nanos() as u64
+}
+
+fn scale(duration: Duration, factor: f64) -> Duration {
+    let secs = duration.as_secs() as f64 + duration.subsec_nanos() as f64 * 1e-9;
+    let nanos = secs * factor * 1e9;
+    Duration::from_nanos(nanos as u64)
+}
@@ -0,0 +1,135 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use clock::{micros, nanos};
+use timing::Parameter;
+use wiring::Symptom;
+
+/// Something a real display would not have tolerated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// RS or RW changed while E was high.
+    ///
+    /// The values from the rising edge were used.
+    ControlChanged,
+    /// D0-D7 changed while E was high.
+    ///
+    /// The value from the falling edge was used.
+    DataChanged { rising: u8, falling: u8 },
+    /// A transfer arrived before the previous one completed.
+    Busy {
+        rs: bool,
+        data: u8,
+        remaining: Duration,
+    },
+    /// A function set of the initialization sequence came too early.
+    ///
+    /// Step 1 is measured from power-on, the others from the previous step.
+    InitTooEarly {
+        step: u8,
+        gap: Duration,
+        required: Duration,
+    },
+    /// The MCU and the controller both drove these data lines during a read.
+    Contention { lines: u8 },
+    /// The traffic suggests a wiring mistake.
+    Miswired(Symptom),
+    /// Pins changed too close to an edge on E.
+    PinTiming {
+        parameter: Parameter,
+        measured: Duration,
+        required: Duration,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Diagnostic::ControlChanged => write!(f, "RS or RW changed while E was high"),
+            Diagnostic::DataChanged { rising, falling } => write!(
+                f,
+                "data changed while E was high: {:#04x} on the rising edge, {:#04x} on the falling edge",
+                rising, falling
+            ),
+            Diagnostic::Busy {
+                rs,
+                data,
+                remaining,
+            } => write!(
+                f,
+                "{} {:#04x} arrived {}us before the controller was ready",
+                if rs { "data" } else { "instruction" },
+                data,
+                micros(remaining)
+            ),
+            Diagnostic::InitTooEarly {
+                step,
+                gap,
+                required,
+            } => write!(
+                f,
+                "initialization step {} came {}us after the {}, {}us required",
+                step,
+                micros(gap),
+                if step == 1 { "power-on" } else { "previous one" },
+                micros(required)
+            ),
+            Diagnostic::Contention { lines } => write!(
+                f,
+                "the MCU and the controller both drove the data lines {:#010b}",
+                lines
+            ),
+            Diagnostic::Miswired(symptom) => write!(f, "{}", symptom.description()),
+            Diagnostic::PinTiming {
+                parameter,
+                measured,
+                required,
+            } => write!(
+                f,
+                "{} was {}ns, {}ns required",
+                parameter.symbol(),
+                nanos(measured),
+                nanos(required)
+            ),
+        }
+    }
+}
+
+/// Shared log of diagnostics.
+///
+/// Nothing is kept until a handle is requested with `listen`,
+/// so that reports don't pile up when nobody reads them.
+#[derive(Clone, Default)]
+pub struct Diagnostics {
+    log: Rc<RefCell<Vec<Diagnostic>>>,
+    listening: Rc<Cell<bool>>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Diagnostics::default()
+    }
+
+    /// Returns a handle on the log, which keeps reports from now on.
+    pub fn listen(&self) -> Diagnostics {
+        self.listening.set(true);
+        self.clone()
+    }
+
+    pub fn report(&self, diagnostic: Diagnostic) {
+        if self.listening.get() {
+            self.log.borrow_mut().push(diagnostic);
+        }
+    }
+
+    /// Returns and clears the diagnostics reported so far.
+    pub fn take(&self) -> Vec<Diagnostic> {
+        ::std::mem::take(&mut *self.log.borrow_mut())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.log.borrow().is_empty()
+    }
+}
@@ -0,0 +1,187 @@
+use gpio_traits::pin::{Input, Output, PinState};
+
+use pin::{BitPin, Line, Mode, Pin};
+use rng::Rng;
+use Simulator;
+
+/// Something wrong with a single line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fault {
+    /// Shorted to Vcc: writes are ignored, reads see high.
+    StuckHigh,
+    /// Shorted to GND: writes are ignored, reads see low.
+    StuckLow,
+    /// A cracked joint: each write is lost with this probability,
+    /// and the line floats until the next write gets through.
+    Intermittent(f64),
+    /// Each write bounces once with this probability.
+    Glitch(f64),
+}
+
+/// Faults to inject when wiring a driver.
+///
+/// Random faults are seeded, so a run can be replayed.
+#[derive(Clone, Debug, Default)]
+pub struct Faults {
+    seed: u64,
+    lines: Vec<(Line, Fault)>,
+    swaps: Vec<(u8, u8)>,
+}
+
+impl Faults {
+    pub fn new(seed: u64) -> Self {
+        Faults {
+            seed: seed,
+            lines: Vec::new(),
+            swaps: Vec::new(),
+        }
+    }
+
+    /// Adds a fault on a line, as seen from the display.
+    pub fn fault(mut self, line: Line, fault: Fault) -> Self {
+        if let Line::D(i) = line {
+            assert!(i < 8, "no data line D{}", i);
+        }
+        self.lines.push((line, fault));
+        self
+    }
+
+    /// Crosses two data lines.
+    pub fn swap(mut self, a: u8, b: u8) -> Self {
+        for &i in &[a, b] {
+            assert!(i < 8, "no data line D{}", i);
+        }
+        self.swaps.push((a, b));
+        self
+    }
+
+    /// Returns the display data line reached by the MCU's data pin `i`.
+    pub fn target(&self, i: u8) -> u8 {
+        self.swaps.iter().fold(i, |i, &(a, b)| if i == a {
+            b
+        } else if i == b {
+            a
+        } else {
+            i
+        })
+    }
+
+    /// Wraps the pin driving the given line.
+    pub fn wrap<P: Output + Contact>(&self, line: Line, pin: P) -> Faulty<P> {
+        let fault = self.lines
+            .iter()
+            .rev()
+            .find(|&&(l, _)| l == line)
+            .map(|&(_, fault)| fault);
+        Faulty::new(pin, fault, self.seed ^ (index(line) << 32))
+    }
+}
+
+fn index(line: Line) -> u64 {
+    match line {
+        Line::D(i) => i as u64,
+        Line::Rs => 8,
+        Line::Rw => 9,
+        Line::E => 10,
+        Line::Backlight => 11,
+        Line::V0 => 12,
+    }
+}
+
+/// A pin whose contact with the line can open.
+pub trait Contact {
+    /// Opens or closes the contact. Lines that can't float ignore it.
+    fn set_open(&mut self, _open: bool) {}
+}
+
+impl Contact for BitPin {
+    fn set_open(&mut self, open: bool) {
+        BitPin::set_open(self, open);
+    }
+}
+
+impl Contact for Pin {}
+
+impl Contact for Simulator {}
+
+/// A pin with an optional fault.
+pub struct Faulty<P> {
+    pin: P,
+    fault: Option<Fault>,
+    rng: Rng,
+}
+
+impl<P: Output + Contact> Faulty<P> {
+    pub fn new(mut pin: P, fault: Option<Fault>, seed: u64) -> Self {
+        match fault {
+            Some(Fault::StuckHigh) => pin.high(),
+            Some(Fault::StuckLow) => pin.low(),
+            _ => (),
+        }
+        Faulty {
+            pin: pin,
+            fault: fault,
+            rng: Rng::new(seed),
+        }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.pin
+    }
+
+    fn set(&mut self, high: bool) {
+        match self.fault {
+            Some(Fault::StuckHigh) | Some(Fault::StuckLow) => return,
+            Some(Fault::Intermittent(probability)) if self.rng.next_f64() < probability => {
+                self.pin.set_open(true);
+                return;
+            }
+            Some(Fault::Intermittent(_)) => self.pin.set_open(false),
+            Some(Fault::Glitch(probability)) if self.rng.next_f64() < probability => {
+                // Settle, bounce back, settle again
+                write(&mut self.pin, high);
+                write(&mut self.pin, !high);
+            }
+            _ => (),
+        }
+        write(&mut self.pin, high);
+    }
+}
+
+fn write<P: Output>(pin: &mut P, high: bool) {
+    if high {
+        pin.high();
+    } else {
+        pin.low();
+    }
+}
+
+impl Faulty<BitPin> {
+    pub fn mode(&self) -> Mode {
+        self.pin.mode()
+    }
+
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.pin.set_mode(mode);
+    }
+}
+
+impl<P: Output + Contact> Output for Faulty<P> {
+    fn high(&mut self) {
+        self.set(true);
+    }
+
+    fn low(&mut self) {
+        self.set(false);
+    }
+}
+
+impl<P: Input> Input for Faulty<P> {
+    fn read(&self) -> PinState {
+        match self.fault {
+            Some(Fault::StuckHigh) => PinState::High,
+            Some(Fault::StuckLow) => PinState::Low,
+            _ => self.pin.read(),
+        }
+    }
+}
@@ -1,920 +0,0 @@
-[
-    // Space
-   [0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0],
-    // `!`
-   [0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00000,
-    0b00000,
-    0b00100,
-    0],
-    // `"`
-   [0b01010,
-    0b01010,
-    0b01010,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0],
-    // `#`
-   [0b01010,
-    0b01010,
-    0b11111,
-    0b01010,
-    0b11111,
-    0b01010,
-    0b01010,
-    0],
-    // `$`
-   [0b00100,
-    0b01111,
-    0b10100,
-    0b01110,
-    0b00101,
-    0b11110,
-    0b00100,
-    0],
-    // `%`
-   [0b11000,
-    0b11001,
-    0b00010,
-    0b00100,
-    0b01000,
-    0b10011,
-    0b00011,
-    0],
-    // `&`
-   [0b01100,
-    0b10010,
-    0b10100,
-    0b01000,
-    0b10101,
-    0b10010,
-    0b01101,
-    0],
-    // `'`
-   [0b01100,
-    0b00100,
-    0b01000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0],
-    // `(`
-   [0b00010,
-    0b00100,
-    0b01000,
-    0b01000,
-    0b01000,
-    0b00100,
-    0b00010,
-    0],
-    // `)`
-   [0b01000,
-    0b00100,
-    0b00010,
-    0b00010,
-    0b00010,
-    0b00100,
-    0b01000,
-    0],
-    // `*`
-   [0b00000,
-    0b00100,
-    0b10101,
-    0b01110,
-    0b10101,
-    0b00100,
-    0b00000,
-    0],
-    // `+`
-   [0b00000,
-    0b00100,
-    0b00100,
-    0b11111,
-    0b00100,
-    0b00100,
-    0b00000,
-    0],
-    // `,`
-   [0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b01100,
-    0b00100,
-    0b01000,
-    0],
-    // `-`
-   [0b00000,
-    0b00000,
-    0b00000,
-    0b11111,
-    0b00000,
-    0b00000,
-    0b00000,
-    0],
-    // `.`
-   [0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b01100,
-    0b01100,
-    0],
-    // `/`
-   [0b00000,
-    0b00001,
-    0b00010,
-    0b00100,
-    0b01000,
-    0b10000,
-    0b00000,
-    0],
-    // `0`
-   [0b01110,
-    0b10001,
-    0b10011,
-    0b10101,
-    0b11001,
-    0b10001,
-    0b01110,
-    0],
-    // `1`
-   [0b00100,
-    0b01100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b01110,
-    0],
-    // `2`
-   [0b01110,
-    0b10001,
-    0b00001,
-    0b00010,
-    0b00100,
-    0b01000,
-    0b11111,
-    0],
-    // `3`
-   [0b11111,
-    0b00010,
-    0b00100,
-    0b00010,
-    0b00001,
-    0b10001,
-    0b01110,
-    0],
-    // `4`
-   [0b00010,
-    0b00110,
-    0b01010,
-    0b10010,
-    0b11111,
-    0b00010,
-    0b00010,
-    0],
-    // `5`
-   [0b11111,
-    0b10000,
-    0b11110,
-    0b00001,
-    0b00001,
-    0b10001,
-    0b01110,
-    0],
-    // `6`
-   [0b00110,
-    0b01000,
-    0b10000,
-    0b11110,
-    0b10001,
-    0b10001,
-    0b01110,
-    0],
-    // `7`
-   [0b11111,
-    0b10001,
-    0b00001,
-    0b00010,
-    0b00100,
-    0b00100,
-    0b00100,
-    0],
-    // `8`
-   [0b01110,
-    0b10001,
-    0b10001,
-    0b01110,
-    0b10001,
-    0b10001,
-    0b01110,
-    0],
-    // `9`
-   [0b01110,
-    0b10001,
-    0b10001,
-    0b01111,
-    0b00001,
-    0b00010,
-    0b01100,
-    0],
-    // `:`
-   [0b00000,
-    0b01100,
-    0b01100,
-    0b00000,
-    0b01100,
-    0b01100,
-    0b00000,
-    0],
-    // `;`
-   [0b00000,
-    0b01100,
-    0b01100,
-    0b00000,
-    0b01100,
-    0b00100,
-    0b01000,
-    0],
-    // `<`
-   [0b00010,
-    0b00100,
-    0b01000,
-    0b10000,
-    0b01000,
-    0b00100,
-    0b00010,
-    0],
-    // `=`
-   [0b00000,
-    0b00000,
-    0b11111,
-    0b00000,
-    0b11111,
-    0b00000,
-    0b00000,
-    0],
-    // `>`
-   [0b01000,
-    0b00100,
-    0b00010,
-    0b00001,
-    0b00010,
-    0b00100,
-    0b01000,
-    0],
-    // `?`
-   [0b01110,
-    0b10001,
-    0b00001,
-    0b00010,
-    0b00100,
-    0b00000,
-    0b00100,
-    0],
-    // `@`
-   [0b01110,
-    0b10001,
-    0b00001,
-    0b01101,
-    0b10101,
-    0b10101,
-    0b01110,
-    0],
-    // `A`
-   [0b01110,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b11111,
-    0b10001,
-    0b10001,
-    0],
-    // `B`
-   [0b11110,
-    0b10001,
-    0b10001,
-    0b11110,
-    0b10001,
-    0b10001,
-    0b11110,
-    0],
-    // `C`
-   [0b01110,
-    0b10001,
-    0b10000,
-    0b10000,
-    0b10000,
-    0b10001,
-    0b01110,
-    0],
-    // `D`
-   [0b11100,
-    0b10010,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10010,
-    0b11100,
-    0],
-    // `E`
-   [0b11111,
-    0b10000,
-    0b10000,
-    0b11110,
-    0b10000,
-    0b10000,
-    0b11111,
-    0],
-    // `F`
-   [0b11111,
-    0b10000,
-    0b10000,
-    0b11110,
-    0b10000,
-    0b10000,
-    0b10000,
-    0],
-    // `G`
-   [0b01110,
-    0b10001,
-    0b10000,
-    0b10111,
-    0b10001,
-    0b10001,
-    0b01111,
-    0],
-    // `H`
-   [0b10001,
-    0b10001,
-    0b10001,
-    0b11111,
-    0b10001,
-    0b10001,
-    0b10001,
-    0],
-    // `I`
-   [0b01110,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b01110,
-    0],
-    // `J`
-   [0b00111,
-    0b00010,
-    0b00010,
-    0b00010,
-    0b00010,
-    0b10010,
-    0b01100,
-    0,
-    ],
-    // `K`
-   [0b10001,
-    0b10010,
-    0b10100,
-    0b11000,
-    0b10100,
-    0b10010,
-    0b10001,
-    0,
-    ],
-    // `L`
-   [0b10000,
-    0b10000,
-    0b10000,
-    0b10000,
-    0b10000,
-    0b10000,
-    0b11111,
-    0,
-    ],
-    // `M`
-   [0b10001,
-    0b11011,
-    0b10101,
-    0b10101,
-    0b10001,
-    0b10001,
-    0b10001,
-    0,
-    ],
-    // `N`
-   [0b10001,
-    0b10001,
-    0b11001,
-    0b10101,
-    0b10011,
-    0b10001,
-    0b10001,
-    0,
-    ],
-    // `O`
-   [0b01110,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b01110,
-    0,
-    ],
-    // `P`
-   [0b11110,
-    0b10001,
-    0b10001,
-    0b11110,
-    0b10000,
-    0b10000,
-    0b10000,
-    0,
-    ],
-    // `Q`
-   [0b01110,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10101,
-    0b10010,
-    0b01101,
-    0,
-    ],
-    // `R`
-   [0b11110,
-    0b10001,
-    0b10001,
-    0b11110,
-    0b10100,
-    0b10010,
-    0b10001,
-    0,
-    ],
-    // `S`
-   [0b01111,
-    0b10000,
-    0b10000,
-    0b01110,
-    0b00001,
-    0b00001,
-    0b11110,
-    0,
-    ],
-    // `T`
-   [0b11111,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0,
-    ],
-    // `U`
-   [0b10001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b01110,
-    0,
-    ],
-    // `V`
-   [0b10001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b01010,
-    0b00100,
-    0,
-    ],
-    // `W`
-   [0b10001,
-    0b10001,
-    0b10001,
-    0b10101,
-    0b10101,
-    0b10101,
-    0b01010,
-    0,
-    ],
-    // `X`
-   [0b10001,
-    0b10001,
-    0b01010,
-    0b00100,
-    0b01010,
-    0b10001,
-    0b10001,
-    0,
-    ],
-    // `Y`
-   [0b10001,
-    0b10001,
-    0b10001,
-    0b01010,
-    0b00100,
-    0b00100,
-    0b00100,
-    0,
-    ],
-    // `Z`
-   [0b11111,
-    0b00001,
-    0b00010,
-    0b00100,
-    0b01000,
-    0b10000,
-    0b11111,
-    0,
-    ],
-    // [`
-    [0b11100,
-    0b10000,
-    0b10000,
-    0b10000,
-    0b10000,
-    0b10000,
-    0b11100,
-    0,
-    ],
-    // yen
-   [0b10001,
-    0b01010,
-    0b11111,
-    0b00100,
-    0b11111,
-    0b00100,
-    0b00100,
-    0,
-    ],
-    // `]`
-   [0b01110,
-    0b00010,
-    0b00010,
-    0b00010,
-    0b00010,
-    0b00010,
-    0b01110,
-    0,
-    ],
-    // `^`
-   [0b00100,
-    0b01010,
-    0b10001,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0,
-    ],
-    // `_`
-   [0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b11111,
-    0,
-    ],
-    // '`'
-   [0b01000,
-    0b00100,
-    0b00010,
-    0b00000,
-    0b00000,
-    0b00000,
-    0b00000,
-    0,
-    ],
-    // `a`
-   [0b00000,
-    0b00000,
-    0b01110,
-    0b00001,
-    0b01111,
-    0b10001,
-    0b01111,
-    0,
-    ],
-    // `b`
-   [0b10000,
-    0b10000,
-    0b10110,
-    0b11001,
-    0b10001,
-    0b10001,
-    0b11110,
-    0,
-    ],
-    // `c`
-   [0b00000,
-    0b00000,
-    0b01110,
-    0b10000,
-    0b10000,
-    0b10001,
-    0b01110,
-    0,
-    ],
-    // `d`
-   [0b00001,
-    0b00001,
-    0b01101,
-    0b10011,
-    0b10001,
-    0b10001,
-    0b01111,
-    0,
-    ],
-    // `e`
-   [0b00000,
-    0b00000,
-    0b01110,
-    0b10001,
-    0b11111,
-    0b10000,
-    0b01110,
-    0,
-    ],
-    // `f`
-   [0b00110,
-    0b01001,
-    0b01000,
-    0b11100,
-    0b01000,
-    0b01000,
-    0b01000,
-    0,
-    ],
-    // `g`
-   [0b00000,
-    0b01111,
-    0b10001,
-    0b10001,
-    0b01111,
-    0b00001,
-    0b01110,
-    0,
-    ],
-    // `h`
-   [0b10000,
-    0b10000,
-    0b10110,
-    0b11001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0,
-    ],
-    // `i`
-   [0b00100,
-    0b00000,
-    0b01100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b01110,
-    0,
-    ],
-    // `j`
-   [0b00010,
-    0b00000,
-    0b00110,
-    0b00010,
-    0b00010,
-    0b10010,
-    0b01100,
-    0,
-    ],
-    // `k`
-   [0b10000,
-    0b10000,
-    0b10010,
-    0b10100,
-    0b11000,
-    0b10100,
-    0b10010,
-    0,
-    ],
-    // `l`
-   [0b01100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b01110,
-    0,
-    ],
-    // `m`
-   [0b00000,
-    0b00000,
-    0b11010,
-    0b10101,
-    0b10101,
-    0b10001,
-    0b10001,
-    0,
-    ],
-    // `n`
-   [0b00000,
-    0b00000,
-    0b10110,
-    0b11001,
-    0b10001,
-    0b10001,
-    0b10001,
-    0,
-    ],
-    // `o`
-   [0b00000,
-    0b00000,
-    0b01110,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b01110,
-    0,
-    ],
-    // `p`
-   [0b00000,
-    0b00000,
-    0b11110,
-    0b10001,
-    0b11110,
-    0b10000,
-    0b10000,
-    0,
-    ],
-    // `q`
-   [0b00000,
-    0b00000,
-    0b01101,
-    0b10011,
-    0b01111,
-    0b00001,
-    0b00001,
-    0,
-    ],
-    // `r`
-   [0b00000,
-    0b00000,
-    0b10110,
-    0b11001,
-    0b10000,
-    0b10000,
-    0b10000,
-    0,
-    ],
-    // `s`
-   [0b00000,
-    0b00000,
-    0b01110,
-    0b10000,
-    0b01110,
-    0b00001,
-    0b11110,
-    0,
-    ],
-    // `t`
-   [0b01000,
-    0b01000,
-    0b11100,
-    0b01000,
-    0b01000,
-    0b01001,
-    0b00110,
-    0,
-    ],
-    // `u`
-   [0b00000,
-    0b00000,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b10011,
-    0b01101,
-    0,
-    ],
-    // `v`
-   [0b00000,
-    0b00000,
-    0b10001,
-    0b10001,
-    0b10001,
-    0b01010,
-    0b00100,
-    0,
-    ],
-    // `w`
-   [0b00000,
-    0b00000,
-    0b10001,
-    0b10101,
-    0b10101,
-    0b10101,
-    0b01010,
-    0,
-    ],
-    // `x`
-   [0b00000,
-    0b00000,
-    0b10001,
-    0b01010,
-    0b00100,
-    0b01010,
-    0b10001,
-    0,
-    ],
-    // `y`
-   [0b00000,
-    0b00000,
-    0b10001,
-    0b10001,
-    0b01111,
-    0b00001,
-    0b01110,
-    0,
-    ],
-    // `z`
-   [0b00000,
-    0b00000,
-    0b11111,
-    0b00010,
-    0b00100,
-    0b01000,
-    0b11111,
-    0,
-    ],
-    // `{`
-   [0b00010,
-    0b00100,
-    0b00100,
-    0b01000,
-    0b00100,
-    0b00100,
-    0b00010,
-    0,
-    ],
-    // `|`
-   [0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0b00100,
-    0,
-    ],
-    // `}`
-   [0b01000,
-    0b00100,
-    0b00100,
-    0b00010,
-    0b00100,
-    0b00100,
-    0b01000,
-    0,
-    ],
-    // left arrow
-   [0b00000,
-    0b00100,
-    0b00010,
-    0b11111,
-    0b00010,
-    0b00100,
-    0b00000,
-    0,
-    ],
-    // right arrow
-   [0b00000,
-    0b00100,
-    0b01000,
-    0b11111,
-    0b01000,
-    0b00100,
-    0b00000,
-    0,
-    ],
-]
@@ -0,0 +1,3128 @@
+[
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Space
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `!`
+   [0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00000,
+    0b00000,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `"`
+   [0b01010,
+    0b01010,
+    0b01010,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `#`
+   [0b01010,
+    0b01010,
+    0b11111,
+    0b01010,
+    0b11111,
+    0b01010,
+    0b01010,
+    0,
+    0,
+    0,
+    0],
+    // `$`
+   [0b00100,
+    0b01111,
+    0b10100,
+    0b01110,
+    0b00101,
+    0b11110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `%`
+   [0b11000,
+    0b11001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b10011,
+    0b00011,
+    0,
+    0,
+    0,
+    0],
+    // `&`
+   [0b01100,
+    0b10010,
+    0b10100,
+    0b01000,
+    0b10101,
+    0b10010,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `'`
+   [0b01100,
+    0b00100,
+    0b01000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `(`
+   [0b00010,
+    0b00100,
+    0b01000,
+    0b01000,
+    0b01000,
+    0b00100,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `)`
+   [0b01000,
+    0b00100,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `*`
+   [0b00000,
+    0b00100,
+    0b10101,
+    0b01110,
+    0b10101,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `+`
+   [0b00000,
+    0b00100,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `,`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `-`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `.`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b01100,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `/`
+   [0b00000,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `0`
+   [0b01110,
+    0b10001,
+    0b10011,
+    0b10101,
+    0b11001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `1`
+   [0b00100,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `2`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `3`
+   [0b11111,
+    0b00010,
+    0b00100,
+    0b00010,
+    0b00001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `4`
+   [0b00010,
+    0b00110,
+    0b01010,
+    0b10010,
+    0b11111,
+    0b00010,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `5`
+   [0b11111,
+    0b10000,
+    0b11110,
+    0b00001,
+    0b00001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `6`
+   [0b00110,
+    0b01000,
+    0b10000,
+    0b11110,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `7`
+   [0b11111,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `8`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `9`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b00010,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `:`
+   [0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `;`
+   [0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `<`
+   [0b00010,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b01000,
+    0b00100,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `=`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `>`
+   [0b01000,
+    0b00100,
+    0b00010,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `?`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b00000,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `@`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b01101,
+    0b10101,
+    0b10101,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `A`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `B`
+   [0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0,
+    0,
+    0,
+    0],
+    // `C`
+   [0b01110,
+    0b10001,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `D`
+   [0b11100,
+    0b10010,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10010,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `E`
+   [0b11111,
+    0b10000,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `F`
+   [0b11111,
+    0b10000,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `G`
+   [0b01110,
+    0b10001,
+    0b10000,
+    0b10111,
+    0b10001,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `H`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `I`
+   [0b01110,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `J`
+   [0b00111,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b10010,
+    0b01100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `K`
+   [0b10001,
+    0b10010,
+    0b10100,
+    0b11000,
+    0b10100,
+    0b10010,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `L`
+   [0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `M`
+   [0b10001,
+    0b11011,
+    0b10101,
+    0b10101,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `N`
+   [0b10001,
+    0b10001,
+    0b11001,
+    0b10101,
+    0b10011,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `O`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `P`
+   [0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `Q`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10101,
+    0b10010,
+    0b01101,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `R`
+   [0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10100,
+    0b10010,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `S`
+   [0b01111,
+    0b10000,
+    0b10000,
+    0b01110,
+    0b00001,
+    0b00001,
+    0b11110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `T`
+   [0b11111,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `U`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `V`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `W`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b01010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `X`
+   [0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `Y`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `Z`
+   [0b11111,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // [`
+    [0b11100,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b11100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // yen
+   [0b10001,
+    0b01010,
+    0b11111,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `]`
+   [0b01110,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `^`
+   [0b00100,
+    0b01010,
+    0b10001,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `_`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // '`'
+   [0b01000,
+    0b00100,
+    0b00010,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `a`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `b`
+   [0b10000,
+    0b10000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b11110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `c`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10000,
+    0b10000,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `d`
+   [0b00001,
+    0b00001,
+    0b01101,
+    0b10011,
+    0b10001,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `e`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10000,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `f`
+   [0b00110,
+    0b01001,
+    0b01000,
+    0b11100,
+    0b01000,
+    0b01000,
+    0b01000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `g`
+   [0b00000,
+    0b01111,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `h`
+   [0b10000,
+    0b10000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `i`
+   [0b00100,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `j`
+   [0b00010,
+    0b00000,
+    0b00110,
+    0b00010,
+    0b00010,
+    0b10010,
+    0b01100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `k`
+   [0b10000,
+    0b10000,
+    0b10010,
+    0b10100,
+    0b11000,
+    0b10100,
+    0b10010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `l`
+   [0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `m`
+   [0b00000,
+    0b00000,
+    0b11010,
+    0b10101,
+    0b10101,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `n`
+   [0b00000,
+    0b00000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `o`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `p`
+   [0b00000,
+    0b00000,
+    0b11110,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `q`
+   [0b00000,
+    0b00000,
+    0b01101,
+    0b10011,
+    0b01111,
+    0b00001,
+    0b00001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `r`
+   [0b00000,
+    0b00000,
+    0b10110,
+    0b11001,
+    0b10000,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `s`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10000,
+    0b01110,
+    0b00001,
+    0b11110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `t`
+   [0b01000,
+    0b01000,
+    0b11100,
+    0b01000,
+    0b01000,
+    0b01001,
+    0b00110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `u`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b01101,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `v`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `w`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b01010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `x`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `y`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `z`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `{`
+   [0b00010,
+    0b00100,
+    0b00100,
+    0b01000,
+    0b00100,
+    0b00100,
+    0b00010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `|`
+   [0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `}`
+   [0b01000,
+    0b00100,
+    0b00100,
+    0b00010,
+    0b00100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // right arrow
+   [0b00000,
+    0b00100,
+    0b00010,
+    0b11111,
+    0b00010,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // left arrow
+   [0b00000,
+    0b00100,
+    0b01000,
+    0b11111,
+    0b01000,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `。`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b11100,
+    0b10100,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `「`
+   [0b00111,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `」`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `、`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b10000,
+    0b01000,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `・`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `ヲ`
+   [0b00000,
+    0b11111,
+    0b00001,
+    0b11111,
+    0b00001,
+    0b00010,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ァ`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b00001,
+    0b00110,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ィ`
+   [0b00000,
+    0b00000,
+    0b00010,
+    0b00100,
+    0b01100,
+    0b10100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ゥ`
+   [0b00000,
+    0b00000,
+    0b00100,
+    0b11111,
+    0b10001,
+    0b00001,
+    0b00110,
+    0,
+    0,
+    0,
+    0],
+    // `ェ`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `ォ`
+   [0b00000,
+    0b00000,
+    0b00010,
+    0b11111,
+    0b00110,
+    0b01010,
+    0b10010,
+    0,
+    0,
+    0,
+    0],
+    // `ャ`
+   [0b00000,
+    0b00000,
+    0b01000,
+    0b11111,
+    0b01001,
+    0b01010,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ュ`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b01110,
+    0b00010,
+    0b00010,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `ョ`
+   [0b00000,
+    0b00000,
+    0b11110,
+    0b00010,
+    0b11110,
+    0b00010,
+    0b11110,
+    0,
+    0,
+    0,
+    0],
+    // `ッ`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b10101,
+    0b10101,
+    0b00001,
+    0b00110,
+    0,
+    0,
+    0,
+    0],
+    // `ー`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `ア`
+   [0b11111,
+    0b00001,
+    0b00101,
+    0b00110,
+    0b00100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `イ`
+   [0b00001,
+    0b00010,
+    0b00100,
+    0b01100,
+    0b10100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ウ`
+   [0b00100,
+    0b11111,
+    0b10001,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `エ`
+   [0b00000,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `オ`
+   [0b00010,
+    0b11111,
+    0b00010,
+    0b00110,
+    0b01010,
+    0b10010,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `カ`
+   [0b01000,
+    0b11111,
+    0b01001,
+    0b01001,
+    0b01001,
+    0b01001,
+    0b10010,
+    0,
+    0,
+    0,
+    0],
+    // `キ`
+   [0b00100,
+    0b11111,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ク`
+   [0b00000,
+    0b01111,
+    0b01001,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `ケ`
+   [0b01000,
+    0b01111,
+    0b10010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `コ`
+   [0b00000,
+    0b11111,
+    0b00001,
+    0b00001,
+    0b00001,
+    0b00001,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `サ`
+   [0b01010,
+    0b11111,
+    0b01010,
+    0b01010,
+    0b00010,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `シ`
+   [0b00000,
+    0b11000,
+    0b00001,
+    0b11001,
+    0b00001,
+    0b00010,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `ス`
+   [0b00000,
+    0b11111,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01010,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `セ`
+   [0b01000,
+    0b11111,
+    0b01001,
+    0b01010,
+    0b01000,
+    0b01000,
+    0b00111,
+    0,
+    0,
+    0,
+    0],
+    // `ソ`
+   [0b00000,
+    0b10001,
+    0b10001,
+    0b01001,
+    0b00001,
+    0b00010,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `タ`
+   [0b00000,
+    0b01111,
+    0b01001,
+    0b10101,
+    0b00011,
+    0b00010,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `チ`
+   [0b00010,
+    0b11100,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ツ`
+   [0b00000,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b00001,
+    0b00010,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `テ`
+   [0b01110,
+    0b00000,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ト`
+   [0b01000,
+    0b01000,
+    0b01000,
+    0b01100,
+    0b01010,
+    0b01000,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ナ`
+   [0b00100,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b01000,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `ニ`
+   [0b00000,
+    0b01110,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `ヌ`
+   [0b00000,
+    0b11111,
+    0b00001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `ネ`
+   [0b00100,
+    0b11111,
+    0b00010,
+    0b00100,
+    0b01110,
+    0b10101,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ノ`
+   [0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ハ`
+   [0b00000,
+    0b00100,
+    0b00010,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `ヒ`
+   [0b10000,
+    0b10000,
+    0b11111,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `フ`
+   [0b00000,
+    0b11111,
+    0b00001,
+    0b00001,
+    0b00001,
+    0b00010,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `ヘ`
+   [0b00000,
+    0b01000,
+    0b10100,
+    0b00010,
+    0b00001,
+    0b00001,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `ホ`
+   [0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b10101,
+    0b10101,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `マ`
+   [0b00000,
+    0b11111,
+    0b00001,
+    0b00001,
+    0b01010,
+    0b00100,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `ミ`
+   [0b00000,
+    0b01110,
+    0b00000,
+    0b01110,
+    0b00000,
+    0b01110,
+    0b00001,
+    0,
+    0,
+    0,
+    0],
+    // `ム`
+   [0b00000,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b10001,
+    0b11111,
+    0b00001,
+    0,
+    0,
+    0,
+    0],
+    // `メ`
+   [0b00000,
+    0b00001,
+    0b00001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `モ`
+   [0b00000,
+    0b11111,
+    0b01000,
+    0b11111,
+    0b01000,
+    0b01000,
+    0b00111,
+    0,
+    0,
+    0,
+    0],
+    // `ヤ`
+   [0b01000,
+    0b01000,
+    0b11111,
+    0b01001,
+    0b01010,
+    0b01000,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ユ`
+   [0b00000,
+    0b01110,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `ヨ`
+   [0b00000,
+    0b11111,
+    0b00001,
+    0b11111,
+    0b00001,
+    0b00001,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `ラ`
+   [0b01110,
+    0b00000,
+    0b11111,
+    0b00001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `リ`
+   [0b10010,
+    0b10010,
+    0b10010,
+    0b10010,
+    0b00010,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `ル`
+   [0b00000,
+    0b00100,
+    0b10100,
+    0b10100,
+    0b10101,
+    0b10101,
+    0b10110,
+    0,
+    0,
+    0,
+    0],
+    // `レ`
+   [0b00000,
+    0b10000,
+    0b10000,
+    0b10001,
+    0b10010,
+    0b10100,
+    0b11000,
+    0,
+    0,
+    0,
+    0],
+    // `ロ`
+   [0b00000,
+    0b11111,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `ワ`
+   [0b00000,
+    0b11111,
+    0b10001,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ン`
+   [0b00000,
+    0b11000,
+    0b00000,
+    0b00001,
+    0b00001,
+    0b00010,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `゛`
+   [0b00100,
+    0b10010,
+    0b01000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `゜`
+   [0b11100,
+    0b10100,
+    0b11100,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `α`
+   [0b00000,
+    0b00000,
+    0b01001,
+    0b10101,
+    0b10010,
+    0b10010,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `ä`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `β`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b11110,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b10000,
+    0],
+    // `ε`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10000,
+    0b01100,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `µ`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b11101,
+    0b10000,
+    0b10000,
+    0b10000,
+    0],
+    // `σ`
+   [0b00000,
+    0b00000,
+    0b01111,
+    0b10100,
+    0b10010,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ρ`
+   [0b00000,
+    0b00000,
+    0b00110,
+    0b01001,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b10000,
+    0],
+    // `g` with descender
+   [0b00000,
+    0b00000,
+    0b01111,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b00001,
+    0b01110,
+    0],
+    // `√`
+   [0b00000,
+    0b00000,
+    0b00111,
+    0b00100,
+    0b00100,
+    0b10100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `⁻¹`
+   [0b00000,
+    0b00010,
+    0b11010,
+    0b00010,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `j` with descender
+   [0b00000,
+    0b00010,
+    0b00000,
+    0b00110,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b10010,
+    0b01100,
+    0],
+    // `ˣ`
+   [0b00000,
+    0b10100,
+    0b01000,
+    0b10100,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `¢`
+   [0b00000,
+    0b00100,
+    0b01110,
+    0b10100,
+    0b10101,
+    0b01110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `£`
+   [0b01000,
+    0b01000,
+    0b11100,
+    0b01000,
+    0b11100,
+    0b01000,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `ñ`
+   [0b01110,
+    0b00000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `ö`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `p` with descender
+   [0b00000,
+    0b00000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b10000,
+    0],
+    // `q` with descender
+   [0b00000,
+    0b00000,
+    0b01101,
+    0b10011,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b00001,
+    0b00001,
+    0],
+    // `θ`
+   [0b00000,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `∞`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b01011,
+    0b10101,
+    0b11010,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `Ω`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b11011,
+    0,
+    0,
+    0,
+    0],
+    // `ü`
+   [0b01010,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `Σ`
+   [0b11111,
+    0b10000,
+    0b01000,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `π`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b01010,
+    0b01010,
+    0b01010,
+    0b10011,
+    0,
+    0,
+    0,
+    0],
+    // `x̄`
+   [0b11111,
+    0b00000,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `y` with descender
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b00001,
+    0b01110,
+    0],
+    // `千`
+   [0b00000,
+    0b00001,
+    0b11110,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `万`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b01000,
+    0b01111,
+    0b01001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `円`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b10101,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `÷`
+   [0b00000,
+    0b00100,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Full block
+   [0b11111,
+    0b11111,
+    0b11111,
+    0b11111,
+    0b11111,
+    0b11111,
+    0b11111,
+    0b11111,
+    0b11111,
+    0b11111,
+    0],
+]
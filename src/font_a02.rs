@@ -0,0 +1,3125 @@
+[
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // CGRAM
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Right triangle
+   [0b01000,
+    0b01100,
+    0b01110,
+    0b01111,
+    0b01110,
+    0b01100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // Left triangle
+   [0b00010,
+    0b00110,
+    0b01110,
+    0b11110,
+    0b01110,
+    0b00110,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `“`
+   [0b01001,
+    0b10010,
+    0b11011,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `”`
+   [0b11011,
+    0b01001,
+    0b10010,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Double up arrow
+   [0b00100,
+    0b01110,
+    0b11111,
+    0b00000,
+    0b00100,
+    0b01110,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // Double down arrow
+   [0b11111,
+    0b01110,
+    0b00100,
+    0b00000,
+    0b11111,
+    0b01110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // Bullet
+   [0b00000,
+    0b01110,
+    0b11111,
+    0b11111,
+    0b11111,
+    0b01110,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Return
+   [0b00001,
+    0b00001,
+    0b00101,
+    0b01001,
+    0b11111,
+    0b01000,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // Up arrow
+   [0b00100,
+    0b01110,
+    0b10101,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // Down arrow
+   [0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b10101,
+    0b01110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // Right arrow
+   [0b00000,
+    0b00100,
+    0b00010,
+    0b11111,
+    0b00010,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Left arrow
+   [0b00000,
+    0b00100,
+    0b01000,
+    0b11111,
+    0b01000,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `≤`
+   [0b00010,
+    0b00100,
+    0b01000,
+    0b00100,
+    0b00010,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `≥`
+   [0b01000,
+    0b00100,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // Up triangle
+   [0b00000,
+    0b00100,
+    0b00100,
+    0b01110,
+    0b01110,
+    0b11111,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Down triangle
+   [0b00000,
+    0b11111,
+    0b01110,
+    0b01110,
+    0b00100,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Space
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `!`
+   [0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00000,
+    0b00000,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `"`
+   [0b01010,
+    0b01010,
+    0b01010,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `#`
+   [0b01010,
+    0b01010,
+    0b11111,
+    0b01010,
+    0b11111,
+    0b01010,
+    0b01010,
+    0,
+    0,
+    0,
+    0],
+    // `$`
+   [0b00100,
+    0b01111,
+    0b10100,
+    0b01110,
+    0b00101,
+    0b11110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `%`
+   [0b11000,
+    0b11001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b10011,
+    0b00011,
+    0,
+    0,
+    0,
+    0],
+    // `&`
+   [0b01100,
+    0b10010,
+    0b10100,
+    0b01000,
+    0b10101,
+    0b10010,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `'`
+   [0b01100,
+    0b00100,
+    0b01000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `(`
+   [0b00010,
+    0b00100,
+    0b01000,
+    0b01000,
+    0b01000,
+    0b00100,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `)`
+   [0b01000,
+    0b00100,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `*`
+   [0b00000,
+    0b00100,
+    0b10101,
+    0b01110,
+    0b10101,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `+`
+   [0b00000,
+    0b00100,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `,`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `-`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `.`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b01100,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `/`
+   [0b00000,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `0`
+   [0b01110,
+    0b10001,
+    0b10011,
+    0b10101,
+    0b11001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `1`
+   [0b00100,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `2`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `3`
+   [0b11111,
+    0b00010,
+    0b00100,
+    0b00010,
+    0b00001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `4`
+   [0b00010,
+    0b00110,
+    0b01010,
+    0b10010,
+    0b11111,
+    0b00010,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `5`
+   [0b11111,
+    0b10000,
+    0b11110,
+    0b00001,
+    0b00001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `6`
+   [0b00110,
+    0b01000,
+    0b10000,
+    0b11110,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `7`
+   [0b11111,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `8`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `9`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b00010,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `:`
+   [0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `;`
+   [0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `<`
+   [0b00010,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b01000,
+    0b00100,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // `=`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `>`
+   [0b01000,
+    0b00100,
+    0b00010,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0],
+    // `?`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b00000,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `@`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b01101,
+    0b10101,
+    0b10101,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `A`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `B`
+   [0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0,
+    0,
+    0,
+    0],
+    // `C`
+   [0b01110,
+    0b10001,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `D`
+   [0b11100,
+    0b10010,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10010,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `E`
+   [0b11111,
+    0b10000,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `F`
+   [0b11111,
+    0b10000,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `G`
+   [0b01110,
+    0b10001,
+    0b10000,
+    0b10111,
+    0b10001,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `H`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `I`
+   [0b01110,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `J`
+   [0b00111,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b10010,
+    0b01100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `K`
+   [0b10001,
+    0b10010,
+    0b10100,
+    0b11000,
+    0b10100,
+    0b10010,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `L`
+   [0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `M`
+   [0b10001,
+    0b11011,
+    0b10101,
+    0b10101,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `N`
+   [0b10001,
+    0b10001,
+    0b11001,
+    0b10101,
+    0b10011,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `O`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `P`
+   [0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `Q`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10101,
+    0b10010,
+    0b01101,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `R`
+   [0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10100,
+    0b10010,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `S`
+   [0b01111,
+    0b10000,
+    0b10000,
+    0b01110,
+    0b00001,
+    0b00001,
+    0b11110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `T`
+   [0b11111,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `U`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `V`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `W`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b01010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `X`
+   [0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `Y`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `Z`
+   [0b11111,
+    0b00001,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // [`
+    [0b11100,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b11100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `\`
+   [0b00000,
+    0b10000,
+    0b01000,
+    0b00100,
+    0b00010,
+    0b00001,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `]`
+   [0b01110,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b00010,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `^`
+   [0b00100,
+    0b01010,
+    0b10001,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `_`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // '`'
+   [0b01000,
+    0b00100,
+    0b00010,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `a`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `b`
+   [0b10000,
+    0b10000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b11110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `c`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10000,
+    0b10000,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `d`
+   [0b00001,
+    0b00001,
+    0b01101,
+    0b10011,
+    0b10001,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `e`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10000,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `f`
+   [0b00110,
+    0b01001,
+    0b01000,
+    0b11100,
+    0b01000,
+    0b01000,
+    0b01000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `g`
+   [0b00000,
+    0b01111,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `h`
+   [0b10000,
+    0b10000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `i`
+   [0b00100,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `j`
+   [0b00010,
+    0b00000,
+    0b00110,
+    0b00010,
+    0b00010,
+    0b10010,
+    0b01100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `k`
+   [0b10000,
+    0b10000,
+    0b10010,
+    0b10100,
+    0b11000,
+    0b10100,
+    0b10010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `l`
+   [0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `m`
+   [0b00000,
+    0b00000,
+    0b11010,
+    0b10101,
+    0b10101,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `n`
+   [0b00000,
+    0b00000,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `o`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `p`
+   [0b00000,
+    0b00000,
+    0b11110,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `q`
+   [0b00000,
+    0b00000,
+    0b01101,
+    0b10011,
+    0b01111,
+    0b00001,
+    0b00001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `r`
+   [0b00000,
+    0b00000,
+    0b10110,
+    0b11001,
+    0b10000,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `s`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10000,
+    0b01110,
+    0b00001,
+    0b11110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `t`
+   [0b01000,
+    0b01000,
+    0b11100,
+    0b01000,
+    0b01000,
+    0b01001,
+    0b00110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `u`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b01101,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `v`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `w`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b01010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `x`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10001,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `y`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b01110,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `z`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b00010,
+    0b00100,
+    0b01000,
+    0b11111,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `{`
+   [0b00010,
+    0b00100,
+    0b00100,
+    0b01000,
+    0b00100,
+    0b00100,
+    0b00010,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `|`
+   [0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `}`
+   [0b01000,
+    0b00100,
+    0b00100,
+    0b00010,
+    0b00100,
+    0b00100,
+    0b01000,
+    0,
+    0,
+    0,
+    0,
+    ],
+    // `~`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b01101,
+    0b10010,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // House
+   [0b00100,
+    0b01010,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b11111,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `Б`
+   [0b11111,
+    0b10000,
+    0b10000,
+    0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0,
+    0,
+    0,
+    0],
+    // `Д`
+   [0b00110,
+    0b01010,
+    0b01010,
+    0b01010,
+    0b01010,
+    0b11111,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Ж`
+   [0b10101,
+    0b10101,
+    0b10101,
+    0b01110,
+    0b10101,
+    0b10101,
+    0b10101,
+    0,
+    0,
+    0,
+    0],
+    // `З`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b00110,
+    0b00001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `И`
+   [0b10001,
+    0b10001,
+    0b10011,
+    0b10101,
+    0b11001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Й`
+   [0b01010,
+    0b00100,
+    0b10001,
+    0b10011,
+    0b10101,
+    0b11001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Л`
+   [0b01111,
+    0b00101,
+    0b00101,
+    0b00101,
+    0b00101,
+    0b10101,
+    0b01001,
+    0,
+    0,
+    0,
+    0],
+    // `П`
+   [0b11111,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `У`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ц`
+   [0b10010,
+    0b10010,
+    0b10010,
+    0b10010,
+    0b10010,
+    0b11111,
+    0b00001,
+    0,
+    0,
+    0,
+    0],
+    // `Ч`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b00001,
+    0b00001,
+    0,
+    0,
+    0,
+    0],
+    // `Ш`
+   [0b10101,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `Щ`
+   [0b10101,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b11111,
+    0b00001,
+    0,
+    0,
+    0,
+    0],
+    // `Ъ`
+   [0b11000,
+    0b01000,
+    0b01000,
+    0b01110,
+    0b01001,
+    0b01001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ы`
+   [0b10001,
+    0b10001,
+    0b10001,
+    0b11001,
+    0b10101,
+    0b10101,
+    0b11001,
+    0,
+    0,
+    0,
+    0],
+    // `Э`
+   [0b01110,
+    0b10001,
+    0b00001,
+    0b00111,
+    0b00001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `α`
+   [0b00000,
+    0b00000,
+    0b01001,
+    0b10101,
+    0b10010,
+    0b10010,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `♪`
+   [0b00100,
+    0b00110,
+    0b00101,
+    0b00101,
+    0b00100,
+    0b11100,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `Γ`
+   [0b11111,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `π`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b01010,
+    0b01010,
+    0b01010,
+    0b10011,
+    0,
+    0,
+    0,
+    0],
+    // `Σ`
+   [0b11111,
+    0b10000,
+    0b01000,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `σ`
+   [0b00000,
+    0b00000,
+    0b01111,
+    0b10010,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `♬`
+   [0b00011,
+    0b01101,
+    0b01011,
+    0b01001,
+    0b01011,
+    0b11011,
+    0b11000,
+    0,
+    0,
+    0,
+    0],
+    // `τ`
+   [0b00000,
+    0b00000,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b00101,
+    0b00010,
+    0,
+    0,
+    0,
+    0],
+    // Bell
+   [0b00100,
+    0b01110,
+    0b01110,
+    0b01110,
+    0b11111,
+    0b00000,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `Θ`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ω`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01010,
+    0b01010,
+    0b11011,
+    0,
+    0,
+    0,
+    0],
+    // `δ`
+   [0b00110,
+    0b01000,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `∞`
+   [0b00000,
+    0b00000,
+    0b01010,
+    0b10101,
+    0b10101,
+    0b01010,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Heart
+   [0b00000,
+    0b01010,
+    0b11111,
+    0b11111,
+    0b01110,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `ε`
+   [0b00000,
+    0b00000,
+    0b01110,
+    0b10000,
+    0b01100,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `∩`
+   [0b00000,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // Blank
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `¡`
+   [0b00100,
+    0b00000,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `¢`
+   [0b00000,
+    0b00100,
+    0b01110,
+    0b10100,
+    0b10100,
+    0b01110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `£`
+   [0b00110,
+    0b01001,
+    0b01000,
+    0b11100,
+    0b01000,
+    0b01000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `¤`
+   [0b00000,
+    0b10001,
+    0b01110,
+    0b01010,
+    0b01110,
+    0b10001,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `¥`
+   [0b10001,
+    0b01010,
+    0b11111,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `¦`
+   [0b00100,
+    0b00100,
+    0b00100,
+    0b00000,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `§`
+   [0b01110,
+    0b10000,
+    0b01110,
+    0b10001,
+    0b01110,
+    0b00001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ƒ`
+   [0b00011,
+    0b00100,
+    0b01110,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b11000,
+    0,
+    0,
+    0,
+    0],
+    // `©`
+   [0b01110,
+    0b10001,
+    0b10111,
+    0b10100,
+    0b10111,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ª`
+   [0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `«`
+   [0b00000,
+    0b00101,
+    0b01010,
+    0b10100,
+    0b01010,
+    0b00101,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `Ю`
+   [0b10010,
+    0b10101,
+    0b10101,
+    0b11101,
+    0b10101,
+    0b10101,
+    0b10010,
+    0,
+    0,
+    0,
+    0],
+    // `Я`
+   [0b01111,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00101,
+    0b01001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `®`
+   [0b01110,
+    0b11101,
+    0b11011,
+    0b11101,
+    0b11011,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `‘`
+   [0b00010,
+    0b00100,
+    0b00110,
+    0b00000,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `°`
+   [0b01100,
+    0b10010,
+    0b10010,
+    0b01100,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `±`
+   [0b00100,
+    0b00100,
+    0b11111,
+    0b00100,
+    0b00100,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `²`
+   [0b01100,
+    0b00010,
+    0b00100,
+    0b01110,
+    0b00000,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `³`
+   [0b01100,
+    0b00010,
+    0b01100,
+    0b00010,
+    0b01100,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `₧`
+   [0b11000,
+    0b10100,
+    0b11000,
+    0b10010,
+    0b10111,
+    0b10010,
+    0b10011,
+    0,
+    0,
+    0,
+    0],
+    // `µ`
+   [0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b11101,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `¶`
+   [0b01111,
+    0b11101,
+    0b11101,
+    0b01101,
+    0b00101,
+    0b00101,
+    0b00101,
+    0,
+    0,
+    0,
+    0],
+    // `·`
+   [0b00000,
+    0b00000,
+    0b00000,
+    0b01100,
+    0b01100,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `ω`
+   [0b00000,
+    0b00000,
+    0b10001,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b01010,
+    0,
+    0,
+    0,
+    0],
+    // `¹`
+   [0b00100,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0b00000,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `º`
+   [0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0b00000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `»`
+   [0b00000,
+    0b10100,
+    0b01010,
+    0b00101,
+    0b01010,
+    0b10100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `¼`
+   [0b10000,
+    0b10010,
+    0b10100,
+    0b01011,
+    0b10101,
+    0b00111,
+    0b00001,
+    0,
+    0,
+    0,
+    0],
+    // `½`
+   [0b10000,
+    0b10010,
+    0b10100,
+    0b01110,
+    0b10001,
+    0b00010,
+    0b00111,
+    0,
+    0,
+    0,
+    0],
+    // `¾`
+   [0b11000,
+    0b01000,
+    0b11010,
+    0b01101,
+    0b11011,
+    0b00111,
+    0b00001,
+    0,
+    0,
+    0,
+    0],
+    // `¿`
+   [0b00100,
+    0b00000,
+    0b00100,
+    0b01000,
+    0b10000,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `À`
+   [0b01000,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Á`
+   [0b00010,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Â`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Ã`
+   [0b01101,
+    0b10110,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Ä`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Å`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Æ`
+   [0b01111,
+    0b10100,
+    0b10100,
+    0b11110,
+    0b10100,
+    0b10100,
+    0b10111,
+    0,
+    0,
+    0,
+    0],
+    // `Ç`
+   [0b01110,
+    0b10001,
+    0b10000,
+    0b10001,
+    0b01110,
+    0b00100,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `È`
+   [0b01000,
+    0b00100,
+    0b11111,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `É`
+   [0b00010,
+    0b00100,
+    0b11111,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `Ê`
+   [0b00100,
+    0b01010,
+    0b11111,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `Ë`
+   [0b01010,
+    0b00000,
+    0b11111,
+    0b10000,
+    0b11110,
+    0b10000,
+    0b11111,
+    0,
+    0,
+    0,
+    0],
+    // `Ì`
+   [0b01000,
+    0b00100,
+    0b01110,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Í`
+   [0b00010,
+    0b00100,
+    0b01110,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Î`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ï`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b00100,
+    0b00100,
+    0b00100,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ð`
+   [0b11100,
+    0b10010,
+    0b10001,
+    0b11101,
+    0b10001,
+    0b10010,
+    0b11100,
+    0,
+    0,
+    0,
+    0],
+    // `Ñ`
+   [0b01101,
+    0b10110,
+    0b10001,
+    0b11001,
+    0b10101,
+    0b10011,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `Ò`
+   [0b01000,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ó`
+   [0b00010,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ô`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Õ`
+   [0b01101,
+    0b10110,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ö`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `×`
+   [0b00000,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b01010,
+    0b10001,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `Φ`
+   [0b00100,
+    0b01110,
+    0b10101,
+    0b10101,
+    0b10101,
+    0b01110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `Ù`
+   [0b01000,
+    0b00100,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ú`
+   [0b00010,
+    0b00100,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Û`
+   [0b00100,
+    0b01010,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ü`
+   [0b01010,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `Ý`
+   [0b00010,
+    0b00100,
+    0b10001,
+    0b01010,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `Þ`
+   [0b10000,
+    0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10000,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `ß`
+   [0b01100,
+    0b10010,
+    0b10010,
+    0b10110,
+    0b10001,
+    0b10001,
+    0b10110,
+    0,
+    0,
+    0,
+    0],
+    // `à`
+   [0b01000,
+    0b00100,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `á`
+   [0b00010,
+    0b00100,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `â`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `ã`
+   [0b01101,
+    0b10110,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `ä`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `å`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b00001,
+    0b01111,
+    0b10001,
+    0b01111,
+    0,
+    0,
+    0,
+    0],
+    // `æ`
+   [0b00000,
+    0b00000,
+    0b11010,
+    0b00101,
+    0b01111,
+    0b10100,
+    0b11011,
+    0,
+    0,
+    0,
+    0],
+    // `ç`
+   [0b00000,
+    0b01110,
+    0b10000,
+    0b10001,
+    0b01110,
+    0b00100,
+    0b01100,
+    0,
+    0,
+    0,
+    0],
+    // `è`
+   [0b01000,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10000,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `é`
+   [0b00010,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10000,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ê`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10000,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ë`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b11111,
+    0b10000,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ì`
+   [0b01000,
+    0b00100,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `í`
+   [0b00010,
+    0b00100,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `î`
+   [0b00100,
+    0b01010,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ï`
+   [0b01010,
+    0b00000,
+    0b00000,
+    0b01100,
+    0b00100,
+    0b00100,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ð`
+   [0b10100,
+    0b01000,
+    0b10100,
+    0b00010,
+    0b01111,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ñ`
+   [0b01101,
+    0b10110,
+    0b10110,
+    0b11001,
+    0b10001,
+    0b10001,
+    0b10001,
+    0,
+    0,
+    0,
+    0],
+    // `ò`
+   [0b01000,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ó`
+   [0b00010,
+    0b00100,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ô`
+   [0b00100,
+    0b01010,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `õ`
+   [0b01101,
+    0b10110,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `ö`
+   [0b01010,
+    0b00000,
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `÷`
+   [0b00000,
+    0b00100,
+    0b00000,
+    0b11111,
+    0b00000,
+    0b00100,
+    0b00000,
+    0,
+    0,
+    0,
+    0],
+    // `φ`
+   [0b00000,
+    0b00100,
+    0b01110,
+    0b10101,
+    0b10101,
+    0b01110,
+    0b00100,
+    0,
+    0,
+    0,
+    0],
+    // `ù`
+   [0b01000,
+    0b00100,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `ú`
+   [0b00010,
+    0b00100,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `û`
+   [0b00100,
+    0b01010,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `ü`
+   [0b01010,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b10011,
+    0b01101,
+    0,
+    0,
+    0,
+    0],
+    // `ý`
+   [0b00010,
+    0b00100,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+    // `þ`
+   [0b00000,
+    0b10000,
+    0b11110,
+    0b10001,
+    0b10001,
+    0b11110,
+    0b10000,
+    0,
+    0,
+    0,
+    0],
+    // `ÿ`
+   [0b01010,
+    0b00000,
+    0b10001,
+    0b10001,
+    0b01111,
+    0b00001,
+    0b01110,
+    0,
+    0,
+    0,
+    0],
+]
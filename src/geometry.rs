@@ -0,0 +1,71 @@
+/// Character layout of a display module.
+///
+/// Each cell of the glass shows a fixed DDRAM line and column,
+/// shifted along with the display.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Geometry {
+    /// 8 characters on a single line.
+    G8x1,
+    /// 16 characters on a single line, in 1-line mode.
+    G16x1,
+    /// 16 characters on a single line, in 2-line mode:
+    /// the right half shows the second line (0x40-0x47).
+    G16x1Split,
+    G16x2,
+    /// Rows 3 and 4 continue rows 1 and 2, from column 16.
+    G16x4,
+    G20x2,
+    /// Rows 3 and 4 continue rows 1 and 2, from column 20.
+    G20x4,
+    G24x2,
+    G40x2,
+    /// Two controllers: the first drives rows 1 and 2, the second rows 3 and 4.
+    G40x4,
+}
+
+impl Geometry {
+    pub fn columns(self) -> usize {
+        match self {
+            Geometry::G8x1 => 8,
+            Geometry::G16x1 | Geometry::G16x1Split | Geometry::G16x2 | Geometry::G16x4 => 16,
+            Geometry::G20x2 | Geometry::G20x4 => 20,
+            Geometry::G24x2 => 24,
+            Geometry::G40x2 | Geometry::G40x4 => 40,
+        }
+    }
+
+    pub fn rows(self) -> usize {
+        match self {
+            Geometry::G8x1 | Geometry::G16x1 | Geometry::G16x1Split => 1,
+            Geometry::G16x2 | Geometry::G20x2 | Geometry::G24x2 | Geometry::G40x2 => 2,
+            Geometry::G16x4 | Geometry::G20x4 | Geometry::G40x4 => 4,
+        }
+    }
+
+    /// Number of controllers on the module.
+    pub fn controllers(self) -> usize {
+        match self {
+            Geometry::G40x4 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the controller driving the given row.
+    pub fn controller(self, row: usize) -> usize {
+        match self {
+            Geometry::G40x4 => row / 2,
+            _ => 0,
+        }
+    }
+
+    /// Returns the DDRAM line and column shown at the given cell,
+    /// before any display shift, on the controller driving this row.
+    pub fn cell(self, row: usize, column: usize) -> (usize, usize) {
+        match self {
+            Geometry::G16x1Split if column >= 8 => (1, column - 8),
+            Geometry::G16x4 | Geometry::G20x4 => (row % 2, column + (row / 2) * self.columns()),
+            Geometry::G40x4 => (row % 2, column),
+            _ => (row, column),
+        }
+    }
+}
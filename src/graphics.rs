@@ -1,11 +1,16 @@
+use clock::{micros, Clock};
+use geometry::Geometry;
 use lcd_hd44780;
-use lcd_hd44780::commands::{Direction, TextDirection};
+use lcd_hd44780::commands::{CharacterGrid, Direction, LineCount, TextDirection};
 use piston_window::*;
 
 use std::io::Cursor;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+/// Time between the two phases of the blinking cursor, with fosc = 250kHz.
+pub const BLINK_INTERVAL_US: u64 = 409_600;
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Point {
     pub x: usize,
@@ -13,36 +18,86 @@ pub struct Point {
 }
 
 pub enum AddressCounter {
-    Ddram { line: u8, addr: u8 },
-    Cgram { cell: u8, addr: u8 },
+    // DDRAM addresses are 7 bits wide, but not all of them are valid.
+    Ddram { addr: u8 },
+    // CGRAM addresses are 6 bits wide.
+    Cgram { addr: u8 },
 }
 
 impl AddressCounter {
-    pub fn shift(&mut self, direction: Direction) {
+    pub fn shift(&mut self, direction: Direction, lines: &LineCount) {
         match self {
-            &mut AddressCounter::Ddram {
-                ref mut line,
-                ref mut addr,
-            } => if shift_offset(addr, 40, direction) {
-                *line = 1 - *line;
-            },
-            &mut AddressCounter::Cgram {
-                ref mut cell,
-                ref mut addr,
-            } => if shift_offset(addr, 8, direction) {
-                match direction {
-                    Direction::Left => *cell -= 1,
-                    Direction::Right => *cell += 1,
-                }
-            },
+            &mut AddressCounter::Ddram { ref mut addr } => {
+                *addr = next_ddram_address(*addr, direction, lines);
+            }
+            &mut AddressCounter::Cgram { ref mut addr } => {
+                shift_offset(addr, 64, direction);
+            }
+        }
+    }
+
+    /// Returns the 7-bit address, as read back with the busy flag.
+    pub fn address(&self) -> u8 {
+        match *self {
+            AddressCounter::Ddram { addr } => addr,
+            AddressCounter::Cgram { addr } => addr,
+        }
+    }
+}
+
+/// Moves a DDRAM address by one, jumping between the valid ranges.
+///
+/// * 1 line: 0x00-0x4F
+/// * 2 lines: 0x00-0x27, then 0x40-0x67
+///
+/// Invalid addresses simply count up or down within 7 bits.
+pub fn next_ddram_address(addr: u8, direction: Direction, lines: &LineCount) -> u8 {
+    let next = match direction {
+        Direction::Left => addr.wrapping_sub(1),
+        Direction::Right => addr.wrapping_add(1),
+    } & 0x7F;
+
+    match *lines {
+        LineCount::One => match (addr, direction) {
+            (0x4F, Direction::Right) => 0x00,
+            (0x00, Direction::Left) => 0x4F,
+            _ => next,
+        },
+        LineCount::Two => match (addr, direction) {
+            (0x27, Direction::Right) => 0x40,
+            (0x67, Direction::Right) => 0x00,
+            (0x40, Direction::Left) => 0x27,
+            (0x00, Direction::Left) => 0x67,
+            _ => next,
+        },
+    }
+}
+
+/// Character ROM variant, fixed when the controller is made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RomCode {
+    /// Japanese: katakana and symbols.
+    A00,
+    /// European: Western European, Greek and Cyrillic.
+    A02,
+}
+
+impl RomCode {
+    pub fn glyphs(self) -> [[u8; 11]; 256] {
+        match self {
+            RomCode::A00 => include!("font_a00.rs"),
+            RomCode::A02 => include!("font_a02.rs"),
         }
     }
 }
 
 pub struct GraphicData {
-    pub ddram: [[u8; 40]; 2],
-    pub cgram: [[u8; 8]; 8],
-    pub cgrom: [[u8; 8]; 96],
+    // 80 bytes: one 80-character line, or two 40-character lines.
+    pub ddram: [u8; 80],
+    // 64 bytes: eight 8-row slots, or four 16-row slots in 5x10 mode.
+    pub cgram: [u8; 64],
+    // 10 rows and the cursor row, with room for descenders.
+    pub cgrom: [[u8; 11]; 256],
     pub characters: lcd_hd44780::commands::CharacterGrid,
     pub lines: lcd_hd44780::commands::LineCount,
 
@@ -62,9 +117,9 @@ pub struct GraphicData {
 impl GraphicData {
     pub fn new() -> Self {
         GraphicData {
-            ddram: [[0x20; 40]; 2],
-            cgram: [[0; 8]; 8],
-            cgrom: include!("font.rs"),
+            ddram: [0x20; 80],
+            cgram: [0; 64],
+            cgrom: RomCode::A00.glyphs(),
 
             auto_shift: false,
             text_direction: TextDirection::LeftToRight,
@@ -72,7 +127,7 @@ impl GraphicData {
             characters: lcd_hd44780::commands::CharacterGrid::C5x8,
             lines: lcd_hd44780::commands::LineCount::Two,
 
-            ac: AddressCounter::Ddram { line: 0, addr: 0 },
+            ac: AddressCounter::Ddram { addr: 0 },
 
             offset: 0,
             display: true,
@@ -81,31 +136,176 @@ impl GraphicData {
         }
     }
 
+    /// Applies the internal reset, as run at power-on.
+    ///
+    /// CGRAM is left alone.
+    pub fn reset(&mut self) {
+        // Display clear
+        self.ddram = [0x20; 80];
+        self.ac = AddressCounter::Ddram { addr: 0 };
+        self.offset = 0;
+        // Function set: 1 line, 5x8 dots
+        self.lines = LineCount::One;
+        self.characters = CharacterGrid::C5x8;
+        // Display off
+        self.display = false;
+        self.cursor = false;
+        self.blink = false;
+        // Entry mode: increment, no shift
+        self.text_direction = TextDirection::LeftToRight;
+        self.auto_shift = false;
+    }
+
+    /// Number of characters in each DDRAM line.
+    pub fn line_length(&self) -> u8 {
+        match self.lines {
+            LineCount::One => 80,
+            LineCount::Two => 40,
+        }
+    }
+
+    /// Number of dot rows in each character, including the cursor row.
+    pub fn rows(&self) -> usize {
+        match self.characters {
+            CharacterGrid::C5x8 => 8,
+            CharacterGrid::C5x10 => 11,
+        }
+    }
+
+    /// Returns the dot rows for the given character code.
+    pub fn glyph(&self, code: u8) -> &[u8] {
+        let rows = self.rows();
+        if code < 0x10 {
+            // Bit 3 is ignored: 0x08-0x0F mirror 0x00-0x07.
+            // In 5x10 mode, the lowest bit of the code is ignored as well.
+            let slot = code as usize & 0x07;
+            let start = match self.characters {
+                CharacterGrid::C5x8 => slot * 8,
+                CharacterGrid::C5x10 => (slot >> 1) * 16,
+            };
+            &self.cgram[start..start + rows]
+        } else {
+            // 0x10-0x1F come from the ROM too (blank in A00).
+            &self.cgrom[code as usize][..rows]
+        }
+    }
+
+    /// Returns the DDRAM content of each line.
+    pub fn lines(&self) -> Vec<&[u8]> {
+        match self.lines {
+            LineCount::One => vec![&self.ddram[..]],
+            LineCount::Two => vec![&self.ddram[..40], &self.ddram[40..]],
+        }
+    }
+
+    /// Returns the line and column of a DDRAM address, if it is valid.
+    pub fn ddram_position(&self, addr: u8) -> Option<(usize, usize)> {
+        let addr = addr as usize;
+        match self.lines {
+            LineCount::One if addr < 0x50 => Some((0, addr)),
+            LineCount::Two if addr < 0x28 => Some((0, addr)),
+            LineCount::Two if (0x40..0x68).contains(&addr) => Some((1, addr - 0x40)),
+            _ => None,
+        }
+    }
+
+    /// Returns the DDRAM line and column under the cursor, if any.
+    pub fn cursor_position(&self) -> Option<(usize, usize)> {
+        match self.ac {
+            AddressCounter::Ddram { addr } => self.ddram_position(addr),
+            AddressCounter::Cgram { .. } => None,
+        }
+    }
+
+    /// Returns the dots to show for the given DDRAM cell.
+    ///
+    /// `blink_on` is set during the blink phase where the cursor is a full block.
+    pub fn dots(&self, line: usize, addr: usize, blink_on: bool) -> [u8; 11] {
+        let mut dots = [0; 11];
+        if !self.display {
+            // DDRAM is kept, but nothing is shown
+            return dots;
+        }
+
+        let rows = self.rows();
+        let code = self.ddram[line * 40 + addr];
+        dots[..rows].copy_from_slice(self.glyph(code));
+
+        if self.cursor_position() == Some((line, addr)) {
+            if self.cursor {
+                // Underline on the last row
+                dots[rows - 1] = 0b11111;
+            }
+            if self.blink && blink_on {
+                for row in &mut dots[..rows] {
+                    *row = 0b11111;
+                }
+            }
+        }
+
+        dots
+    }
+
+    /// Returns the dots shown at the given column of the window,
+    /// with the display shift applied.
+    ///
+    /// Positions on a line the controller is not driving are blank.
+    pub fn shown_dots(&self, line: usize, column: usize, blink_on: bool) -> [u8; 11] {
+        if line >= self.lines().len() {
+            return [0; 11];
+        }
+        let length = self.line_length() as usize;
+        self.dots(line, (column + self.offset as usize) % length, blink_on)
+    }
+
+    /// Shifts the displayed text, on all lines at once.
+    ///
+    /// The cursor follows the text: the address counter does not move.
+    pub fn shift_display(&mut self, direction: Direction) {
+        let length = self.line_length();
+        // The line length may have changed since the last shift
+        self.offset %= length;
+        // Moving the text left moves the window right.
+        shift_offset(&mut self.offset, length, direction.switch());
+    }
+
+    pub fn shift_cursor(&mut self, direction: Direction) {
+        self.ac.shift(direction, &self.lines);
+    }
+
     pub fn write(&mut self, data: u8) {
         match self.ac {
-            AddressCounter::Ddram {
-                ref mut line,
-                ref mut addr,
-            } => {
-                self.ddram[*line as usize][*addr as usize] = data;
-                // Also shift the display maybe?
+            AddressCounter::Ddram { addr } => {
+                // Writes to invalid addresses are lost
+                if let Some((line, column)) = self.ddram_position(addr) {
+                    self.ddram[line * 40 + column] = data;
+                }
+                // With I/D=1, the display shifts left to keep the cursor in place
                 if self.auto_shift {
-                    shift_offset(
-                        &mut self.offset,
-                        40,
-                        self.text_direction.direction().switch(),
-                    );
+                    let direction = self.text_direction.direction().switch();
+                    self.shift_display(direction);
                 }
             }
-            AddressCounter::Cgram {
-                ref mut cell,
-                ref mut addr,
-            } => {
-                self.cgram[*cell as usize][*addr as usize] = data;
+            AddressCounter::Cgram { addr } => {
+                self.cgram[addr as usize] = data;
             }
         }
         // Also shift the counter
-        self.ac.shift(self.text_direction.direction());
+        let direction = self.text_direction.direction();
+        self.shift_cursor(direction);
+    }
+
+    /// Returns the byte under the counter, or `None` for an invalid address.
+    pub fn read(&mut self) -> Option<u8> {
+        let data = match self.ac {
+            AddressCounter::Ddram { addr } => self.ddram_position(addr)
+                .map(|(line, column)| self.ddram[line * 40 + column]),
+            AddressCounter::Cgram { addr } => Some(self.cgram[addr as usize]),
+        };
+        // Reads move the counter, but never shift the display
+        let direction = self.text_direction.direction();
+        self.shift_cursor(direction);
+        data
     }
 }
 
@@ -131,13 +331,57 @@ pub fn shift_offset(offset: &mut u8, max: u8, direction: lcd_hd44780::commands::
     }
 }
 
-pub fn start_graphics(data: Arc<Mutex<GraphicData>>) {
-    thread::spawn(|| run_graphics(data));
+/// Opens a window showing the given controllers on a single glass.
+///
+/// `geometry` decides which controller drives each row.
+/// Cursor blinking follows the simulated clock.
+pub fn start_graphics(controllers: Vec<Arc<Mutex<GraphicData>>>, geometry: Geometry, clock: Clock) {
+    thread::spawn(move || run_graphics(controllers, geometry, clock));
+}
+
+/// Returns the dots shown in each cell of a row of the glass.
+///
+/// Rows whose controller is missing from `controllers` stay blank.
+pub fn row_dots(
+    controllers: &[Arc<Mutex<GraphicData>>],
+    geometry: Geometry,
+    row: usize,
+    blink_on: bool,
+) -> Vec<Vec<u8>> {
+    let data = match controllers.get(geometry.controller(row)) {
+        Some(data) => data.lock().unwrap(),
+        None => return vec![vec![0; 8]; geometry.columns()],
+    };
+    (0..geometry.columns())
+        .map(|column| {
+            let (line, addr) = geometry.cell(row, column);
+            data.shown_dots(line, addr, blink_on)[..data.rows()].to_vec()
+        })
+        .collect()
 }
 
-fn run_graphics(data: Arc<Mutex<GraphicData>>) {
-    let w = 483;
-    let h = 206;
+fn run_graphics(controllers: Vec<Arc<Mutex<GraphicData>>>, geometry: Geometry, clock: Clock) {
+    let pixel_size = 4;
+    let pixel_spacing = 1;
+    let pixel_fill = pixel_size - pixel_spacing;
+
+    let char_spacing = 3;
+
+    // 5x10 characters only exist on single-line modules
+    let dot_rows = if geometry.rows() == 1 { 11 } else { 8 };
+    let row_height = dot_rows * pixel_size + char_spacing;
+    let glass = Point {
+        x: geometry.columns() * (pixel_size * 5 + char_spacing) + 2 * 12,
+        y: geometry.rows() * row_height + 2 * 12,
+    };
+
+    // The picture only fits a 16x2 module
+    let background = geometry == Geometry::G16x2;
+    let (w, h) = if background {
+        (483, 206)
+    } else {
+        (glass.x as u32 + 60, glass.y as u32 + 60)
+    };
     let mut window: PistonWindow = WindowSettings::new("hd44780 simulator", [w, h])
         .exit_on_esc(true)
         .build()
@@ -150,93 +394,83 @@ fn run_graphics(data: Arc<Mutex<GraphicData>>) {
         img.as_rgba8().unwrap(),
         &TextureSettings::new(),
     ).unwrap();
-    let offset = Point { x: 60, y: 66 };
-
-    let pixel_size = 4;
-    let pixel_spacing = 1;
-    let pixel_fill = pixel_size - pixel_spacing;
-
-    // TODO: make this will depend on font?...
-    let char_fill = Point {
-        x: pixel_size * 5,
-        y: pixel_size * 8,
-    };
-    let char_spacing = 3;
-    let char_size = Point {
-        x: char_fill.x + char_spacing,
-        y: char_fill.y + char_spacing,
+    let offset = if background {
+        Point { x: 60, y: 66 }
+    } else {
+        Point { x: 42, y: 42 }
     };
 
     let color = [1.0, 1.0, 1.0, 0.9];
-    let background = [0.0, 0.0, 0.0, 0.2];
+    let unlit = [0.0, 0.0, 0.0, 0.2];
+    let bezel = [0.05, 0.05, 0.05, 1.0];
+    let glass_color = [0.1, 0.35, 1.0, 1.0];
 
     while let Some(e) = window.next() {
+        let elapsed_us = micros(clock.now());
+        let blink_on = (elapsed_us / BLINK_INTERVAL_US).is_multiple_of(2);
+
         window.draw_2d(&e, |c, g| {
-            image(&texture, c.transform, g);
-
-            // Draw the two lines
-            let data = data.lock().unwrap();
-
-            let first_line = &data.ddram[0];
-            let second_line = &data.ddram[1];
-
-            let mut draw_char = |character: &[u8; 8], offset: Point| {
-                for (y, &line) in character.iter().enumerate() {
-                    for x in 0..5 {
-                        let color = if (line & 1 << x) != 0 {
-                            color
-                        } else {
-                            background
-                        };
-                        // The most significant bit is actually the left size
-                        // So mirror it all
-                        let x = 4 - x;
-                        rectangle(
-                            color,
-                            [
-                                (offset.x + x * pixel_size) as f64,
-                                (offset.y + y * pixel_size) as f64,
-                                pixel_fill as f64,
-                                pixel_fill as f64,
-                            ],
-                            c.transform,
-                            g,
-                        );
-                    }
-                }
-            };
+            if background {
+                image(&texture, c.transform, g);
+            } else {
+                clear(bezel, g);
+                rectangle(
+                    glass_color,
+                    [30.0, 30.0, glass.x as f64, glass.y as f64],
+                    c.transform,
+                    g,
+                );
+            }
 
-            let mut draw_line = |line: &[u8], offset: Point| for (i, &code) in line.iter()
-                .skip(data.offset as usize)
-                .chain(line.iter())
-                .take(16)
-                .enumerate()
-            {
-                let character = if code < 8 {
-                    &data.cgram[code as usize]
-                } else if code >= 32 {
-                    &data.cgrom[code as usize - 32]
-                } else {
-                    panic!("Bad character code: {}", code);
+            for row in 0..geometry.rows() {
+                let cells = row_dots(&controllers, geometry, row, blink_on);
+
+                // Cells are taller in 5x10 mode
+                let char_fill = Point {
+                    x: pixel_size * 5,
+                    y: pixel_size * cells[0].len(),
+                };
+                let char_size = Point {
+                    x: char_fill.x + char_spacing,
+                    y: char_fill.y + char_spacing,
                 };
 
-                draw_char(
-                    character,
-                    Point {
-                        x: offset.x + i * char_size.x,
-                        y: offset.y,
-                    },
-                );
-            };
+                let mut draw_char = |character: &[u8], offset: Point| {
+                    for (y, &line) in character.iter().enumerate() {
+                        for x in 0..5 {
+                            let color = if (line & 1 << x) != 0 {
+                                color
+                            } else {
+                                unlit
+                            };
+                            // The most significant bit is actually the left size
+                            // So mirror it all
+                            let x = 4 - x;
+                            rectangle(
+                                color,
+                                [
+                                    (offset.x + x * pixel_size) as f64,
+                                    (offset.y + y * pixel_size) as f64,
+                                    pixel_fill as f64,
+                                    pixel_fill as f64,
+                                ],
+                                c.transform,
+                                g,
+                            );
+                        }
+                    }
+                };
 
-            draw_line(first_line, offset);
-            draw_line(
-                second_line,
-                Point {
-                    x: offset.x,
-                    y: offset.y + char_size.y,
-                },
-            );
+                for (column, dots) in cells.iter().enumerate() {
+                    draw_char(
+                        dots,
+                        Point {
+                            x: offset.x + column * char_size.x,
+                            y: offset.y + row * char_size.y,
+                        },
+                    );
+                }
+            }
         });
     }
 }
@@ -3,47 +3,179 @@ extern crate image;
 extern crate lcd_hd44780;
 extern crate piston_window;
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+pub mod clock;
+pub mod diagnostic;
+pub mod fault;
+pub mod geometry;
 pub mod graphics;
 pub mod pin;
+mod rng;
+pub mod timing;
+pub mod vcd;
+pub mod wiring;
 
+use clock::Clock;
+use diagnostic::{Diagnostic, Diagnostics};
+use fault::{Faults, Faulty};
+use geometry::Geometry;
 use gpio_traits::pin::PinState;
-use pin::{BitPin, Pin};
+use pin::{BitPin, DataBus, FirstChange, Line, Pin, Stamp};
+use rng::Rng;
+use timing::{BusyPolicy, Parameter, Supply};
+use vcd::Trace;
+use wiring::{WiredPin, Wiring};
 
-pub struct Sleep;
+/// Advances the simulated clock instead of sleeping.
+pub struct Sleep {
+    clock: Clock,
+}
 
 pub use lcd_hd44780::Driver;
 pub type SimulatedDriver = lcd_hd44780::PinDriver<Pin, Pin, ([BitPin; 8], Simulator), Sleep>;
+pub type SimulatedDriver4Bit = lcd_hd44780::PinDriver<Pin, Pin, ([BitPin; 4], Simulator), Sleep>;
+pub type FaultyDriver = lcd_hd44780::PinDriver<
+    Faulty<Pin>,
+    Faulty<Pin>,
+    ([Faulty<BitPin>; 8], Faulty<Simulator>),
+    Sleep,
+>;
+pub type FaultyDriver4Bit = lcd_hd44780::PinDriver<
+    Faulty<Pin>,
+    Faulty<Pin>,
+    ([Faulty<BitPin>; 4], Faulty<Simulator>),
+    Sleep,
+>;
+pub type WiredDriver = lcd_hd44780::PinDriver<WiredPin, WiredPin, ([WiredPin; 8], WiredPin), Sleep>;
+pub type WiredDriver4Bit =
+    lcd_hd44780::PinDriver<WiredPin, WiredPin, ([WiredPin; 4], WiredPin), Sleep>;
 
 impl lcd_hd44780::Sleep for Sleep {
     fn sleep(&mut self, us: u32) {
-        let millis = std::time::Duration::new(0, 1000 * us);
-        std::thread::sleep(millis);
+        self.clock.advance(Duration::from_micros(us as u64));
+    }
+}
+
+/// State of the controller when power comes up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerOn {
+    /// Ready to use: 8-bit interface, two lines, display on.
+    Ready,
+    /// The internal reset ran, as described in the datasheet:
+    /// 8-bit interface, one line, display off, busy for 10ms.
+    Reset,
+    /// The supply rose too slowly for the internal reset:
+    /// registers, RAM and interface mode are random.
+    FailedReset { seed: u64 },
+}
+
+/// Disturbs the interface, as an MCU reset or a missed edge would.
+#[derive(Clone, Default)]
+pub struct Desync {
+    low_nibble: Rc<Cell<bool>>,
+    dropped_pulses: Rc<Cell<u32>>,
+}
+
+impl Desync {
+    /// Takes the next 4-bit transfer as the low nibble of a byte.
+    ///
+    /// This is where the controller ends up when the MCU resets between two nibbles.
+    /// It has no effect in 8-bit mode.
+    pub fn expect_low_nibble(&self) {
+        self.low_nibble.set(true);
+    }
+
+    /// Makes the controller miss the next E pulse.
+    pub fn drop_pulse(&self) {
+        self.dropped_pulses.set(self.dropped_pulses.get() + 1);
     }
 }
 
 enum BitMode {
     EightBits,
+    // 4-bit interface, waiting for the high nibble
     FourBits,
+    // 4-bit interface, waiting for the low nibble
     FourBits2 { buffer: u8 },
 }
 
+/// A simulated display controller, driven through its pins.
+///
+/// Handles such as `diagnostics()` are shared with the simulator: take them
+/// before handing it to a driver, which keeps it out of reach.
 pub struct Simulator {
     graphics: Arc<Mutex<graphics::GraphicData>>,
+    geometry: Geometry,
 
     enable: bool,
     rs: Rc<Cell<PinState>>,
     rw: Rc<Cell<PinState>>,
 
+    // Sampled on the rising edge of E
+    rs_latch: PinState,
+    rw_latch: PinState,
+    data_latch: u8,
+
     bit_mode: BitMode,
     data: Rc<Cell<u8>>,
+    // Low nibble of a 4-bit read, for the second pulse
+    read_buffer: Option<u8>,
+
+    // Levels the MCU outputs, the lines it drives,
+    // and the ones the controller drives during a read
+    data_levels: Rc<Cell<u8>>,
+    data_driven: Rc<Cell<u8>>,
+    driving: Rc<Cell<u8>>,
+    floating: Rc<Cell<u8>>,
+    contention: Rc<Cell<u8>>,
+
+    // When the MCU last changed RS/RW and D0-D7
+    control_changed: Stamp,
+    data_changed: Stamp,
+    // First change of RS/RW and D0-D7 since the last falling edge
+    control_first: FirstChange,
+    data_first: FirstChange,
+    rose_at: Duration,
+    fell_at: Option<Duration>,
+    supply: Supply,
+    edge_time: Duration,
+
+    clock: Clock,
+    busy_until: Duration,
+    busy_policy: BusyPolicy,
+    rng: Rng,
+
+    // Function sets with DL=1 in a row, and when the last one came
+    init_step: u8,
+    init_at: Duration,
+
+    wiring_check: Option<wiring::Check>,
+    trace: Trace,
+    // Which E wire of the trace is this controller's
+    trace_enable: usize,
+
+    desync: Desync,
+    // Set while E is high for a pulse the controller misses
+    dropping: bool,
+
+    diagnostics: Diagnostics,
 }
 
 impl gpio_traits::pin::Output for Simulator {
     fn low(&mut self) {
+        if !self.enable {
+            return;
+        }
+
         self.enable = false;
+        self.tick();
+        self.trace.enable(self.trace_enable, false);
+        self.fall();
+        self.trace.sample();
+        self.tick();
     }
 
     fn high(&mut self) {
@@ -52,20 +184,244 @@ impl gpio_traits::pin::Output for Simulator {
         }
 
         self.enable = true;
+        self.tick();
+        self.trace.enable(self.trace_enable, true);
+        self.rise();
+        self.trace.sample();
+        self.tick();
+    }
+}
+
+impl Simulator {
+    /// Ends a cycle: writes are latched here.
+    fn fall(&mut self) {
+        if self.dropping {
+            self.dropping = false;
+            return;
+        }
+
+        let now = self.now();
+        self.check_timing(Parameter::EnablePulse, self.rose_at, now);
+        self.fell_at = Some(now);
+        self.control_first.set(None);
+        self.data_first.set(None);
+
+        if pin::is_high(self.rs.get()) != pin::is_high(self.rs_latch)
+            || pin::is_high(self.rw.get()) != pin::is_high(self.rw_latch)
+        {
+            self.diagnostics.report(Diagnostic::ControlChanged);
+        }
+
+        if pin::is_high(self.rw_latch) {
+            // The controller released the bus
+            let lines = self.contention.get();
+            if lines != 0 {
+                self.diagnostics.report(Diagnostic::Contention { lines: lines });
+            }
+            self.driving.set(0);
+            self.data_bus().settle();
+            return;
+        }
+
+        // Writes are latched on the falling edge
+        if let Some(changed) = self.data_changed.get() {
+            self.check_timing(Parameter::DataSetup, changed, now);
+        }
+        let data = self.data.get();
+        if let Some(ref mut check) = self.wiring_check {
+            let rs = pin::is_high(self.rs_latch);
+            for symptom in check.observe(rs, data, self.data_driven.get()) {
+                self.diagnostics.report(Diagnostic::Miswired(symptom));
+            }
+        }
+        if data != self.data_latch {
+            self.diagnostics.report(Diagnostic::DataChanged {
+                rising: self.data_latch,
+                falling: data,
+            });
+        }
+        self.check_init(data);
+        self.transfer(false);
+    }
+
+    /// Starts a cycle: RS and RW are sampled here.
+    fn rise(&mut self) {
+        let dropped = self.desync.dropped_pulses.get();
+        if dropped > 0 {
+            self.desync.dropped_pulses.set(dropped - 1);
+            self.dropping = true;
+            return;
+        }
+
+        if self.desync.low_nibble.get() {
+            self.desync.low_nibble.set(false);
+            if let BitMode::FourBits = self.bit_mode {
+                // Whatever was on D4-D7 before the reset
+                self.bit_mode = BitMode::FourBits2 { buffer: 0 };
+            }
+        }
+
+        // Hold times of the previous cycle, and setup time of this one
+        let now = self.now();
+        if let Some(fell_at) = self.fell_at {
+            if let Some(changed) = self.control_first.get() {
+                self.check_timing(Parameter::AddressHold, fell_at, changed);
+            }
+            if let Some(changed) = self.data_first.get() {
+                if !pin::is_high(self.rw_latch) {
+                    self.check_timing(Parameter::DataHold, fell_at, changed);
+                }
+            }
+        }
+        if let Some(changed) = self.control_changed.get() {
+            self.check_timing(Parameter::AddressSetup, changed, now);
+        }
+        self.rose_at = now;
+
+        self.rs_latch = self.rs.get();
+        self.rw_latch = self.rw.get();
+        self.data_latch = self.data.get();
 
-        let data = match self.bit_mode {
-            BitMode::EightBits => self.data.get(),
+        if pin::is_high(self.rw_latch) {
+            // The controller drives the data bus while E is high,
+            // only D4-D7 in 4-bit mode
+            // Anything both sides drive until E falls is reported then
+            self.contention.set(0);
+            self.transfer(true);
+            self.data_bus().settle();
+        }
+    }
+
+    /// Moves one byte or nibble, depending on the interface mode.
+    fn transfer(&mut self, read: bool) {
+        match self.bit_mode {
+            BitMode::EightBits => if read {
+                let data = self.read();
+                self.put(0xFF, data);
+            } else {
+                let data = self.data.get();
+                self.execute(data);
+            },
             BitMode::FourBits => {
-                self.bit_mode = BitMode::FourBits2 {
-                    buffer: self.data.get(),
+                // High nibble first, on D4-D7
+                let buffer = if read {
+                    let data = self.read();
+                    self.put(0xF0, data);
+                    self.read_buffer = data.map(|data| data << 4);
+                    0
+                } else {
+                    self.data.get() & 0xF0
                 };
-                return;
+                self.bit_mode = BitMode::FourBits2 { buffer: buffer };
             }
-            BitMode::FourBits2 { buffer } => buffer | self.data.get() >> 4,
+            BitMode::FourBits2 { buffer } => {
+                // Low nibble, then wait for the next high nibble.
+                // This must happen before `execute`, which may change the mode.
+                self.bit_mode = BitMode::FourBits;
+                if read {
+                    let data = self.read_buffer.take();
+                    self.put(0xF0, data);
+                } else {
+                    let data = (buffer & 0xF0) | self.data.get() >> 4;
+                    self.execute(data);
+                }
+            }
+        }
+    }
+
+    fn now(&self) -> Duration {
+        self.clock.now()
+    }
+
+    /// Half of the time the MCU spends on an edge of E, before or after it.
+    fn tick(&self) {
+        self.clock.advance(self.edge_time / 2);
+    }
+
+    /// Reports pins that changed too close to an edge on E.
+    fn check_timing(&self, parameter: Parameter, from: Duration, to: Duration) {
+        let measured = to - from;
+        let required = self.supply.minimum(parameter);
+        if measured < required {
+            self.diagnostics.report(Diagnostic::PinTiming {
+                parameter: parameter,
+                measured: measured,
+                required: required,
+            });
+        }
+    }
+
+    fn is_busy(&self) -> bool {
+        self.now() < self.busy_until
+    }
+
+    /// Checks the busy period before a transfer, and starts the next one.
+    ///
+    /// Returns the data to use, or `None` to drop the transfer.
+    fn occupy(&mut self, data: u8) -> Option<u8> {
+        let now = self.now();
+        let rs = pin::is_high(self.rs_latch);
+        let data = if now < self.busy_until {
+            self.diagnostics.report(Diagnostic::Busy {
+                rs: rs,
+                data: data,
+                remaining: self.busy_until - now,
+            });
+            match self.busy_policy {
+                BusyPolicy::Report => data,
+                BusyPolicy::Drop => return None,
+                BusyPolicy::Corrupt { .. } => match self.rng.next_u8() {
+                    0 => data ^ 1,
+                    mask => data ^ mask,
+                },
+            }
+        } else {
+            data
         };
+        self.busy_until = now + timing::execution_time(rs, data);
+        Some(data)
+    }
 
-        // Do stuff here
-        match self.rs.get() {
+    /// Times initialization by instruction, as seen on the bus.
+    ///
+    /// Each pulse counts, with 0x3 on D4-D7 for a function set with DL=1,
+    /// as the controller may be in 4-bit mode when it starts.
+    fn check_init(&mut self, data: u8) {
+        if pin::is_high(self.rs_latch) || data & 0b11110000 != 0b00110000 {
+            // Anything else ends the sequence
+            self.init_step = 0;
+            return;
+        }
+
+        let now = self.now();
+        let (since, required) = match self.init_step {
+            // Time since power-on
+            0 => (Duration::from_secs(0), timing::POWER_ON_US),
+            1 => (self.init_at, timing::INIT_FIRST_US),
+            2 => (self.init_at, timing::INIT_SECOND_US),
+            _ => (now, 0),
+        };
+        let gap = now - since;
+        let required = Duration::from_micros(required);
+        if gap < required {
+            self.diagnostics.report(Diagnostic::InitTooEarly {
+                step: self.init_step + 1,
+                gap: gap,
+                required: required,
+            });
+        }
+        self.init_step = self.init_step.saturating_add(1);
+        self.init_at = now;
+    }
+
+    fn execute(&mut self, data: u8) {
+        self.trace.instruction(pin::is_high(self.rs_latch), false, data);
+        let data = match self.occupy(data) {
+            Some(data) => data,
+            None => return,
+        };
+
+        match self.rs_latch {
             PinState::Low => {
                 // Instruction
                 match data {
@@ -75,14 +431,14 @@ impl gpio_traits::pin::Output for Simulator {
                     0b00000001 => {
                         // Clear display
                         let mut graphics = self.graphics.lock().unwrap();
-                        graphics.ddram = [[0x20; 40]; 2];
-                        graphics.ac = graphics::AddressCounter::Ddram { line: 0, addr: 0 };
+                        graphics.ddram = [0x20; 80];
+                        graphics.ac = graphics::AddressCounter::Ddram { addr: 0 };
                         graphics.offset = 0;
                     }
                     0b00000010...0b00000011 => {
                         // Return home
                         let mut graphics = self.graphics.lock().unwrap();
-                        graphics.ac = graphics::AddressCounter::Ddram { line: 0, addr: 0 };
+                        graphics.ac = graphics::AddressCounter::Ddram { addr: 0 };
                         graphics.offset = 0;
                     }
                     data @ 0b00000100...0b00000111 => {
@@ -103,16 +459,13 @@ impl gpio_traits::pin::Output for Simulator {
                         // Cursor shift = AC shift
                         let mut graphics = self.graphics.lock().unwrap();
                         let direction = lcd_hd44780::commands::Direction::from_u8(data);
-                        graphics.ac.shift(direction);
+                        graphics.shift_cursor(direction);
                     }
                     data @ 0b00011000...0b00011111 => {
                         // Display shift
-                        //
                         let mut graphics = self.graphics.lock().unwrap();
                         let direction = lcd_hd44780::commands::Direction::from_u8(data);
-                        graphics::shift_offset(&mut graphics.offset, 40, direction.switch());
-
-                        // TODO: apply direction to offset
+                        graphics.shift_display(direction);
                     }
                     data @ 0b00100000...0b00111111 => {
                         // Function set
@@ -121,32 +474,31 @@ impl gpio_traits::pin::Output for Simulator {
                         } else {
                             BitMode::FourBits
                         };
-                        // For now, ignore lines / font settings
+                        let mut graphics = self.graphics.lock().unwrap();
+                        let two_lines = (data & 1 << 3) != 0;
+                        graphics.lines = if two_lines {
+                            lcd_hd44780::commands::LineCount::Two
+                        } else {
+                            lcd_hd44780::commands::LineCount::One
+                        };
+                        // 5x10 characters are only available with a single line
+                        graphics.characters = if !two_lines && (data & 1 << 2) != 0 {
+                            lcd_hd44780::commands::CharacterGrid::C5x10
+                        } else {
+                            lcd_hd44780::commands::CharacterGrid::C5x8
+                        };
                     }
                     data @ 0b01000000...0b01111111 => {
                         // Set CGRAM address
                         let mut graphics = self.graphics.lock().unwrap();
-                        let cell = (data & 0b00111000) >> 3;
-                        let addr = data & 0b00000111;
-                        graphics.ac = graphics::AddressCounter::Cgram {
-                            cell: cell,
-                            addr: addr,
-                        };
+                        let addr = data & 0b00111111;
+                        graphics.ac = graphics::AddressCounter::Cgram { addr: addr };
                     }
                     data @ 0b10000000...0b11111111 => {
                         // Set DRAM address
                         let mut graphics = self.graphics.lock().unwrap();
-                        let mut addr = data & 0b01111111;
-                        let line = if addr >= 0x40 {
-                            addr -= 0x40;
-                            1
-                        } else {
-                            0
-                        };
-                        graphics.ac = graphics::AddressCounter::Ddram {
-                            line: line,
-                            addr: addr,
-                        };
+                        let addr = data & 0b01111111;
+                        graphics.ac = graphics::AddressCounter::Ddram { addr: addr };
                     }
                     _ => unreachable!(),
                 }
@@ -158,37 +510,1434 @@ impl gpio_traits::pin::Output for Simulator {
             }
         }
     }
-}
 
-impl Simulator {
+    /// Drives the given lines, leaving the others alone.
+    ///
+    /// With no data, the controller leaves the whole bus alone.
+    fn put(&mut self, lines: u8, data: Option<u8>) {
+        match data {
+            Some(data) => {
+                self.driving.set(lines);
+                self.data.set((self.data.get() & !lines) | (data & lines));
+            }
+            None => self.driving.set(0),
+        }
+    }
+
+    fn read(&mut self) -> Option<u8> {
+        let data = match self.rs_latch {
+            PinState::Low => {
+                // Busy flag and address counter: always available
+                let busy = if self.is_busy() { 1 << 7 } else { 0 };
+                Some(busy | self.graphics.lock().unwrap().ac.address())
+            }
+            PinState::High => {
+                // Nothing drives the bus for invalid addresses
+                let data = self.graphics.lock().unwrap().read();
+                // Reading while busy is a violation too; the data is left alone
+                self.occupy(data.unwrap_or(self.floating.get()));
+                data
+            }
+        };
+        let seen = data.unwrap_or(self.floating.get());
+        self.trace.instruction(pin::is_high(self.rs_latch), true, seen);
+        data
+    }
+
     pub fn new() -> Self {
+        let clock = Clock::new();
+        let rs = pin::new_state();
+        let rw = pin::new_state();
+        // Pulled up inside the controller
+        let data = Rc::new(Cell::new(0xFF));
+        let trace = Trace::new(clock.clone(), rs.clone(), rw.clone(), data.clone());
+        let control_changed = Stamp::traced(clock.clone(), trace.clone());
+        let data_changed = Stamp::traced(clock.clone(), trace.clone());
         Simulator {
             graphics: Arc::new(Mutex::new(graphics::GraphicData::new())),
+            geometry: Geometry::G16x2,
 
             enable: false,
             bit_mode: BitMode::EightBits,
-            rs: pin::new_state(),
-            rw: pin::new_state(),
-            data: Rc::new(Cell::new(0)),
+            read_buffer: None,
+            rs: rs,
+            rw: rw,
+            rs_latch: PinState::Low,
+            rw_latch: PinState::Low,
+            data_latch: 0,
+            data: data,
+
+            data_levels: Rc::new(Cell::new(0)),
+            data_driven: Rc::new(Cell::new(0)),
+            driving: Rc::new(Cell::new(0)),
+            floating: Rc::new(Cell::new(0xFF)),
+            contention: Rc::new(Cell::new(0)),
+
+            control_first: control_changed.watch(),
+            data_first: data_changed.watch(),
+            control_changed: control_changed,
+            data_changed: data_changed,
+            rose_at: Duration::from_secs(0),
+            fell_at: None,
+            supply: Supply::V5,
+            edge_time: Duration::from_nanos(timing::ENABLE_CYCLE_NS / 2),
+
+            clock: clock,
+            busy_until: Duration::from_secs(0),
+            busy_policy: BusyPolicy::Report,
+            rng: Rng::new(0),
+
+            init_step: 0,
+            init_at: Duration::from_secs(0),
+
+            wiring_check: None,
+            trace: trace,
+            trace_enable: 0,
+
+            desync: Desync::default(),
+            dropping: false,
+
+            diagnostics: Diagnostics::new(),
+        }
+    }
+
+    /// Returns a handle on the diagnostics log, shared with controllers on the same bus.
+    ///
+    /// Diagnostics are only kept from the first call on.
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.listen()
+    }
+
+    /// Returns a handle to record the bus as a waveform.
+    pub fn trace(&self) -> Trace {
+        self.trace.clone()
+    }
+
+    /// Returns a handle to knock the interface out of step.
+    pub fn desync(&self) -> Desync {
+        self.desync.clone()
+    }
+
+    /// Returns a handle on the simulated clock, shared with controllers on the same bus.
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
+    /// Paces the simulation against the wall clock, to watch the driver work.
+    ///
+    /// `1.0` runs at hardware speed, `0.01` a hundred times slower.
+    /// Without it, time only passes when the driver sleeps or toggles E.
+    pub fn time_scale(self, time_scale: f64) -> Self {
+        self.clock.set_time_scale(Some(time_scale));
+        self
+    }
+
+    /// Selects the power-on state.
+    pub fn power_on(mut self, power_on: PowerOn) -> Self {
+        match power_on {
+            PowerOn::Ready => (),
+            PowerOn::Reset => {
+                self.graphics.lock().unwrap().reset();
+                self.bit_mode = BitMode::EightBits;
+                self.busy_until = self.now() + Duration::from_micros(timing::RESET_US);
+            }
+            PowerOn::FailedReset { seed } => {
+                let mut rng = Rng::new(seed);
+                self.bit_mode = match rng.next_u8() % 3 {
+                    0 => BitMode::EightBits,
+                    1 => BitMode::FourBits,
+                    _ => BitMode::FourBits2 {
+                        buffer: rng.next_u8() & 0xF0,
+                    },
+                };
+
+                let mut graphics = self.graphics.lock().unwrap();
+                for byte in graphics.ddram.iter_mut() {
+                    *byte = rng.next_u8();
+                }
+                for byte in graphics.cgram.iter_mut() {
+                    *byte = rng.next_u8();
+                }
+                // Function set, with 5x10 dots only on one line
+                let function = rng.next_u8();
+                let two_lines = function & 1 != 0;
+                graphics.lines = if two_lines {
+                    lcd_hd44780::commands::LineCount::Two
+                } else {
+                    lcd_hd44780::commands::LineCount::One
+                };
+                graphics.characters = if !two_lines && function & 2 != 0 {
+                    lcd_hd44780::commands::CharacterGrid::C5x10
+                } else {
+                    lcd_hd44780::commands::CharacterGrid::C5x8
+                };
+                // Display control and entry mode
+                let control = rng.next_u8();
+                graphics.display = control & 1 != 0;
+                graphics.cursor = control & 2 != 0;
+                graphics.blink = control & 4 != 0;
+                graphics.text_direction = lcd_hd44780::commands::TextDirection::from_u8(control);
+                graphics.auto_shift = control & 8 != 0;
+                graphics.ac = graphics::AddressCounter::Ddram {
+                    addr: rng.next_u8() & 0x7F,
+                };
+                graphics.offset = rng.next_u8() % graphics.line_length();
+            }
+        }
+        self
+    }
+
+    /// Fills CGRAM with random contents, as found on real hardware.
+    pub fn random_cgram(self, seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        for byte in self.graphics.lock().unwrap().cgram.iter_mut() {
+            *byte = rng.next_u8();
+        }
+        self
+    }
+
+    /// Selects the bus timing requirements.
+    pub fn supply(mut self, supply: Supply) -> Self {
+        self.supply = supply;
+        self
+    }
+
+    /// Sets how long the MCU takes for each edge on E.
+    ///
+    /// Half of it passes before the edge, half after.
+    /// The default is half of the shortest enable cycle at 5V;
+    /// lower it to see what a fast MCU does.
+    pub fn edge_time(mut self, edge_time: Duration) -> Self {
+        self.edge_time = edge_time;
+        self
+    }
+
+    /// Sets the level of data lines nobody drives.
+    ///
+    /// The controller pulls them up, so the default is `0xFF`.
+    pub fn floating(self, level: u8) -> Self {
+        self.floating.set(level);
+        self.data_bus().settle();
+        self
+    }
+
+    /// Looks for signs of wiring mistakes in the traffic.
+    pub fn check_wiring(mut self) -> Self {
+        self.wiring_check = Some(wiring::Check::default());
+        self
+    }
+
+    /// Selects what happens to transfers arriving while the controller is busy.
+    pub fn busy_policy(mut self, policy: BusyPolicy) -> Self {
+        if let BusyPolicy::Corrupt { seed } = policy {
+            self.rng = Rng::new(seed);
+        }
+        self.busy_policy = policy;
+        self
+    }
+
+    /// Selects the character ROM.
+    pub fn rom(self, rom: graphics::RomCode) -> Self {
+        self.graphics.lock().unwrap().cgrom = rom.glyphs();
+        self
+    }
+
+    /// Selects the module layout shown in the window.
+    pub fn geometry(mut self, geometry: Geometry) -> Self {
+        self.geometry = geometry;
+        self
+    }
+
+    /// Creates another controller on the same RS, RW and data lines.
+    ///
+    /// The new controller starts with default settings, and only latches
+    /// when its own enable line toggles.
+    /// Each one opens its own window when turned into a driver.
+    pub fn share_bus(&self) -> Self {
+        Simulator {
+            graphics: Arc::new(Mutex::new(graphics::GraphicData::new())),
+            geometry: Geometry::G16x2,
+
+            enable: false,
+            bit_mode: BitMode::EightBits,
+            read_buffer: None,
+            rs: self.rs.clone(),
+            rw: self.rw.clone(),
+            rs_latch: PinState::Low,
+            rw_latch: PinState::Low,
+            data_latch: 0,
+            data: self.data.clone(),
+
+            data_levels: self.data_levels.clone(),
+            data_driven: self.data_driven.clone(),
+            driving: self.driving.clone(),
+            floating: self.floating.clone(),
+            contention: self.contention.clone(),
+
+            control_changed: self.control_changed.clone(),
+            data_changed: self.data_changed.clone(),
+            control_first: self.control_changed.watch(),
+            data_first: self.data_changed.watch(),
+            rose_at: Duration::from_secs(0),
+            fell_at: None,
+            supply: self.supply,
+            edge_time: self.edge_time,
+
+            clock: self.clock.clone(),
+            busy_until: Duration::from_secs(0),
+            busy_policy: BusyPolicy::Report,
+            rng: Rng::new(0),
+
+            init_step: 0,
+            init_at: Duration::from_secs(0),
+
+            wiring_check: None,
+            trace: self.trace.clone(),
+            trace_enable: self.trace.add_enable(),
+
+            desync: Desync::default(),
+            dropping: false,
+
+            diagnostics: self.diagnostics.clone(),
         }
     }
 
     pub fn driver() -> SimulatedDriver {
-        let simulator = Simulator::new();
+        Simulator::new().into_driver()
+    }
+
+    pub fn driver_4bit() -> SimulatedDriver4Bit {
+        Simulator::new().into_driver_4bit()
+    }
+
+    /// Opens a 40x4 module, made of two controllers sharing every line but E.
+    ///
+    /// Returns the drivers for E1 (rows 1 and 2) and E2 (rows 3 and 4).
+    pub fn driver_40x4() -> (SimulatedDriver, SimulatedDriver) {
+        let (top, bottom) = Simulator::start_40x4();
+        (top.wire(), bottom.wire())
+    }
+
+    /// Same as `driver_40x4`, wired to D4-D7 only.
+    pub fn driver_40x4_4bit() -> (SimulatedDriver4Bit, SimulatedDriver4Bit) {
+        let (top, bottom) = Simulator::start_40x4();
+        (top.wire_4bit(), bottom.wire_4bit())
+    }
+
+    fn start_40x4() -> (Simulator, Simulator) {
+        let top = Simulator::new().geometry(Geometry::G40x4);
+        let bottom = top.share_bus();
+        graphics::start_graphics(
+            vec![top.graphics.clone(), bottom.graphics.clone()],
+            Geometry::G40x4,
+            top.clock(),
+        );
+        (top, bottom)
+    }
+
+    /// Opens the display window and creates a driver wired to D0-D7.
+    pub fn into_driver(self) -> SimulatedDriver {
+        graphics::start_graphics(vec![self.graphics.clone()], self.geometry, self.clock());
+        self.wire()
+    }
+
+    /// Creates a driver wired to D4-D7 only.
+    ///
+    /// The controller still powers up in 8-bit mode:
+    /// the driver needs to switch it to 4-bit mode.
+    pub fn into_driver_4bit(self) -> SimulatedDriver4Bit {
+        graphics::start_graphics(vec![self.graphics.clone()], self.geometry, self.clock());
+        self.wire_4bit()
+    }
+
+    /// Returns the data lines, to build more pins on the same bus.
+    pub fn data_bus(&self) -> DataBus {
+        DataBus {
+            byte: self.data.clone(),
+            levels: self.data_levels.clone(),
+            driven: self.data_driven.clone(),
+            controller: self.driving.clone(),
+            floating: self.floating.clone(),
+            contention: self.contention.clone(),
+            stamp: self.data_changed.clone(),
+        }
+    }
+
+    /// Opens the display window and creates a driver wired to D0-D7 through faulty lines.
+    pub fn into_faulty_driver(self, faults: &Faults) -> FaultyDriver {
+        graphics::start_graphics(vec![self.graphics.clone()], self.geometry, self.clock());
+        let (rs, rw) = self.faulty_control(faults);
+        let data = [
+            self.faulty_data(faults, 0),
+            self.faulty_data(faults, 1),
+            self.faulty_data(faults, 2),
+            self.faulty_data(faults, 3),
+            self.faulty_data(faults, 4),
+            self.faulty_data(faults, 5),
+            self.faulty_data(faults, 6),
+            self.faulty_data(faults, 7),
+        ];
 
-        let rs = Pin::new(simulator.rs.clone());
-        let rw = Pin::new(simulator.rw.clone());
-        let data = BitPin::new_group(simulator.data.clone());
+        let sleep = Sleep { clock: self.clock() };
+        let enable = faults.wrap(Line::E, self);
+        lcd_hd44780::PinDriver::new(rs, rw, (data, enable), sleep)
+    }
+
+    /// Creates a driver wired to D4-D7 only, through faulty lines.
+    pub fn into_faulty_driver_4bit(self, faults: &Faults) -> FaultyDriver4Bit {
+        graphics::start_graphics(vec![self.graphics.clone()], self.geometry, self.clock());
+        let (rs, rw) = self.faulty_control(faults);
+        let data = [
+            self.faulty_data(faults, 4),
+            self.faulty_data(faults, 5),
+            self.faulty_data(faults, 6),
+            self.faulty_data(faults, 7),
+        ];
+
+        let sleep = Sleep { clock: self.clock() };
+        let enable = faults.wrap(Line::E, self);
+        lcd_hd44780::PinDriver::new(rs, rw, (data, enable), sleep)
+    }
+
+    fn faulty_control(&self, faults: &Faults) -> (Faulty<Pin>, Faulty<Pin>) {
+        let rs = Pin::new(self.rs.clone(), self.control_changed.clone());
+        let rw = Pin::new(self.rw.clone(), self.control_changed.clone());
+        (faults.wrap(Line::Rs, rs), faults.wrap(Line::Rw, rw))
+    }
+
+    /// Returns the MCU's data pin `i`, wherever it ends up.
+    fn faulty_data(&self, faults: &Faults, i: u8) -> Faulty<BitPin> {
+        let target = faults.target(i);
+        faults.wrap(Line::D(target), BitPin::new(self.data_bus(), target))
+    }
+
+    /// Opens the display window and creates a driver on the given MCU pins,
+    /// connected to the display as described by `wiring`.
+    ///
+    /// `data` lists the pins the firmware uses for D0-D7, in order.
+    pub fn into_wired_driver<Id: PartialEq>(
+        self,
+        wiring: &Wiring<Id>,
+        rs: Id,
+        rw: Id,
+        enable: Id,
+        data: [Id; 8],
+    ) -> WiredDriver {
+        graphics::start_graphics(vec![self.graphics.clone()], self.geometry, self.clock());
+        let sleep = Sleep { clock: self.clock() };
+        let simulator = Rc::new(RefCell::new(self));
+        let pin = |id| Simulator::wired_pin(&simulator, wiring, id);
+        let data = [
+            pin(&data[0]),
+            pin(&data[1]),
+            pin(&data[2]),
+            pin(&data[3]),
+            pin(&data[4]),
+            pin(&data[5]),
+            pin(&data[6]),
+            pin(&data[7]),
+        ];
+        lcd_hd44780::PinDriver::new(pin(&rs), pin(&rw), (data, pin(&enable)), sleep)
+    }
+
+    /// Same as `into_wired_driver`, with the pins the firmware uses for D4-D7.
+    pub fn into_wired_driver_4bit<Id: PartialEq>(
+        self,
+        wiring: &Wiring<Id>,
+        rs: Id,
+        rw: Id,
+        enable: Id,
+        data: [Id; 4],
+    ) -> WiredDriver4Bit {
+        graphics::start_graphics(vec![self.graphics.clone()], self.geometry, self.clock());
+        let sleep = Sleep { clock: self.clock() };
+        let simulator = Rc::new(RefCell::new(self));
+        let pin = |id| Simulator::wired_pin(&simulator, wiring, id);
+        let data = [pin(&data[0]), pin(&data[1]), pin(&data[2]), pin(&data[3])];
+        lcd_hd44780::PinDriver::new(pin(&rs), pin(&rw), (data, pin(&enable)), sleep)
+    }
+
+    fn wired_pin<Id: PartialEq>(
+        simulator: &Rc<RefCell<Simulator>>,
+        wiring: &Wiring<Id>,
+        id: &Id,
+    ) -> WiredPin {
+        let this = simulator.borrow();
+        let stamp = this.control_changed.clone();
+        match wiring.line(id) {
+            Some(Line::Rs) => WiredPin::Control(Pin::new(this.rs.clone(), stamp)),
+            Some(Line::Rw) => WiredPin::Control(Pin::new(this.rw.clone(), stamp)),
+            Some(Line::E) => WiredPin::Enable(simulator.clone()),
+            Some(Line::D(i)) => WiredPin::Data(BitPin::new(this.data_bus(), i)),
+            // Not simulated, but they still need a pin
+            Some(Line::Backlight) | Some(Line::V0) => {
+                WiredPin::Control(Pin::new(pin::new_state(), Stamp::new(this.clock())))
+            }
+            None => WiredPin::Unconnected,
+        }
+    }
 
-        graphics::start_graphics(simulator.graphics.clone());
+    fn wire(self) -> SimulatedDriver {
+        let rs = Pin::new(self.rs.clone(), self.control_changed.clone());
+        let rw = Pin::new(self.rw.clone(), self.control_changed.clone());
+        let data = BitPin::new_group(self.data_bus());
 
-        lcd_hd44780::PinDriver::new(rs, rw, (data, simulator), Sleep)
+        let sleep = Sleep { clock: self.clock() };
+        lcd_hd44780::PinDriver::new(rs, rw, (data, self), sleep)
+    }
+
+    fn wire_4bit(self) -> SimulatedDriver4Bit {
+        let rs = Pin::new(self.rs.clone(), self.control_changed.clone());
+        let rw = Pin::new(self.rw.clone(), self.control_changed.clone());
+        let data = BitPin::new_nibble(self.data_bus());
+
+        let sleep = Sleep { clock: self.clock() };
+        lcd_hd44780::PinDriver::new(rs, rw, (data, self), sleep)
     }
 }
 
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use gpio_traits::pin::Output;
+
+    fn wait(simulator: &Simulator) {
+        let now = simulator.now();
+        if simulator.busy_until > now {
+            simulator.clock.advance(simulator.busy_until - now);
+        }
+    }
+
+    fn send(simulator: &mut Simulator, rs: PinState, data: u8) {
+        simulator.rs.set(rs);
+        simulator.rw.set(PinState::Low);
+        simulator.data.set(data);
+        simulator.high();
+        simulator.low();
+        wait(simulator);
+    }
+
+    fn receive(simulator: &mut Simulator, rs: PinState) -> u8 {
+        simulator.rs.set(rs);
+        simulator.rw.set(PinState::High);
+        simulator.high();
+        let data = simulator.data.get();
+        simulator.low();
+        wait(simulator);
+        data
+    }
+
+    fn send_nibble(simulator: &mut Simulator, rs: PinState, nibble: u8) {
+        // D0-D3 are not connected in 4-bit wiring
+        send(simulator, rs, nibble << 4);
+    }
+
+    fn send_4bit(simulator: &mut Simulator, rs: PinState, data: u8) {
+        send_nibble(simulator, rs, data >> 4);
+        send_nibble(simulator, rs, data & 0x0F);
+    }
+
+    fn init_4bit(simulator: &mut Simulator) {
+        for &nibble in &[0x3, 0x3, 0x3, 0x2] {
+            send_nibble(simulator, PinState::Low, nibble);
+        }
+        send_4bit(simulator, PinState::Low, 0b00101000);
+        send_4bit(simulator, PinState::Low, 0b10000000);
+    }
+
+    fn drive<P: Output>(pins: &mut [P], byte: u8) {
+        for (i, pin) in pins.iter_mut().enumerate() {
+            if byte & 1 << i != 0 {
+                pin.high();
+            } else {
+                pin.low();
+            }
+        }
+    }
+
     #[test]
     fn it_works() {}
+
+    #[test]
+    fn read_back() {
+        let mut simulator = Simulator::new();
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x40);
+        send(&mut simulator, PinState::High, b'a');
+        send(&mut simulator, PinState::High, b'b');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x42);
+
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x40);
+        assert_eq!(receive(&mut simulator, PinState::High), b'a');
+        assert_eq!(receive(&mut simulator, PinState::High), b'b');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x42);
+    }
+
+    #[test]
+    fn four_bits() {
+        let mut simulator = Simulator::new();
+        // Initialization by instruction
+        for &nibble in &[0x3, 0x3, 0x3, 0x2] {
+            send_nibble(&mut simulator, PinState::Low, nibble);
+        }
+        send_4bit(&mut simulator, PinState::Low, 0b00101000);
+        send_4bit(&mut simulator, PinState::High, b'a');
+        send_4bit(&mut simulator, PinState::High, b'b');
+
+        send_4bit(&mut simulator, PinState::Low, 0b10000000);
+        let high = receive(&mut simulator, PinState::High) & 0xF0;
+        let low = receive(&mut simulator, PinState::High) >> 4;
+        assert_eq!(high | low, b'a');
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[1], b'b');
+    }
+
+    #[test]
+    fn four_bits_out_of_phase() {
+        let mut simulator = Simulator::new();
+        for &nibble in &[0x3, 0x3, 0x3, 0x2] {
+            send_nibble(&mut simulator, PinState::Low, nibble);
+        }
+        // Stray nibble: the controller now waits for a low nibble.
+        send_nibble(&mut simulator, PinState::Low, 0x0);
+        // The same init sequence brings it back in phase.
+        for &nibble in &[0x3, 0x3, 0x3, 0x2] {
+            send_nibble(&mut simulator, PinState::Low, nibble);
+        }
+        send_4bit(&mut simulator, PinState::High, b'a');
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[0], b'a');
+    }
+
+    #[test]
+    fn one_line() {
+        let mut simulator = Simulator::new();
+        // Function set: 8-bit, 1 line
+        send(&mut simulator, PinState::Low, 0b00110000);
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x4F);
+        send(&mut simulator, PinState::High, b'a');
+        send(&mut simulator, PinState::High, b'b');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x01);
+
+        let graphics = simulator.graphics.lock().unwrap();
+        assert_eq!(graphics.ddram[0x4F], b'a');
+        assert_eq!(graphics.ddram[0x00], b'b');
+        assert_eq!(graphics.lines().len(), 1);
+    }
+
+    #[test]
+    fn five_by_ten() {
+        let mut simulator = Simulator::new();
+        // Function set: 8-bit, 1 line, 5x10
+        send(&mut simulator, PinState::Low, 0b00110100);
+        // Slot 1 starts at CGRAM address 16
+        send(&mut simulator, PinState::Low, 0b01000000 | 16);
+        for row in 0..11 {
+            send(&mut simulator, PinState::High, row);
+        }
+
+        let graphics = simulator.graphics.lock().unwrap();
+        assert_eq!(graphics.rows(), 11);
+        assert_eq!(graphics.glyph(2), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10][..]);
+        assert_eq!(graphics.glyph(3), graphics.glyph(2));
+    }
+
+    #[test]
+    fn rom_upper_half() {
+        let simulator = Simulator::new();
+        {
+            let graphics = simulator.graphics.lock().unwrap();
+            // Degree sign in A00
+            assert_eq!(graphics.glyph(0xDF)[0], 0b11100);
+            assert_eq!(graphics.glyph(0xFF), &[0b11111; 8][..]);
+        }
+
+        let simulator = simulator.rom(graphics::RomCode::A02);
+        let graphics = simulator.graphics.lock().unwrap();
+        // `°` in A02
+        assert_eq!(graphics.glyph(0xB0)[..4], [0b01100, 0b10010, 0b10010, 0b01100]);
+        assert_eq!(graphics.glyph(b'A'), &graphics::RomCode::A00.glyphs()[0x41][..8]);
+    }
+
+    #[test]
+    fn low_codes() {
+        let mut simulator = Simulator::new();
+        send(&mut simulator, PinState::Low, 0b01000000);
+        send(&mut simulator, PinState::High, 0b10101);
+
+        let graphics = simulator.graphics.lock().unwrap();
+        assert_eq!(graphics.glyph(0x08), graphics.glyph(0x00));
+        assert_eq!(graphics.glyph(0x08)[0], 0b10101);
+        for code in 0x10..0x20 {
+            assert_eq!(graphics.glyph(code), &[0; 8][..]);
+        }
+    }
+
+    #[test]
+    fn cursor() {
+        let mut simulator = Simulator::new();
+        send(&mut simulator, PinState::High, b'_');
+        // Display on, cursor on, blink on
+        send(&mut simulator, PinState::Low, 0b00001111);
+        {
+            let graphics = simulator.graphics.lock().unwrap();
+            assert_eq!(graphics.dots(0, 1, false)[7], 0b11111);
+            assert_eq!(graphics.dots(0, 1, true)[..8], [0b11111; 8]);
+            assert_eq!(graphics.dots(0, 0, true)[6], 0b11111);
+        }
+
+        // Display off: DDRAM is kept
+        send(&mut simulator, PinState::Low, 0b00001011);
+        let graphics = simulator.graphics.lock().unwrap();
+        assert_eq!(graphics.dots(0, 0, true), [0; 11]);
+        assert_eq!(graphics.ddram[0], b'_');
+    }
+
+    #[test]
+    fn ddram_wrap() {
+        let mut simulator = Simulator::new();
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x27);
+        send(&mut simulator, PinState::High, b'a');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x40);
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x67);
+        send(&mut simulator, PinState::High, b'b');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x00);
+
+        // Entry mode: decrement
+        send(&mut simulator, PinState::Low, 0b00000100);
+        send(&mut simulator, PinState::High, b'c');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x67);
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x40);
+        send(&mut simulator, PinState::High, b'd');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x27);
+
+        // Invalid addresses store nothing, but reads still move the counter
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x30);
+        send(&mut simulator, PinState::High, b'e');
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x2F);
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x30);
+        receive(&mut simulator, PinState::High);
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x2F);
+
+        let graphics = simulator.graphics.lock().unwrap();
+        assert_eq!(&graphics.ddram[39..41], b"ad");
+        assert_eq!(graphics.ddram[79], b'b');
+        assert_eq!(graphics.ddram[0], b'c');
+        assert!(!graphics.ddram.contains(&b'e'));
+    }
+
+    #[test]
+    fn cgram_wrap() {
+        let mut simulator = Simulator::new();
+        send(&mut simulator, PinState::Low, 0b00000100);
+        send(&mut simulator, PinState::Low, 0b01000000);
+        send(&mut simulator, PinState::High, 1);
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x3F);
+
+        send(&mut simulator, PinState::Low, 0b00000110);
+        send(&mut simulator, PinState::High, 2);
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x00);
+
+        let graphics = simulator.graphics.lock().unwrap();
+        assert_eq!(graphics.cgram[0], 1);
+        assert_eq!(graphics.cgram[0x3F], 2);
+    }
+
+    fn visible(simulator: &Simulator, columns: usize) -> Vec<u8> {
+        let graphics = simulator.graphics.lock().unwrap();
+        let length = graphics.line_length() as usize;
+        (0..columns)
+            .map(|i| graphics.ddram[(graphics.offset as usize + i) % length])
+            .collect()
+    }
+
+    fn cursor_column(simulator: &Simulator) -> Option<usize> {
+        let graphics = simulator.graphics.lock().unwrap();
+        let length = graphics.line_length() as usize;
+        graphics
+            .cursor_position()
+            .map(|(_, addr)| (addr + length - graphics.offset as usize) % length)
+    }
+
+    #[test]
+    fn display_shift() {
+        // Example from the datasheet: 8-bit operation, 8-digit x 1-line display
+        let steps: &[(PinState, u8, &[u8; 8], usize)] = &[
+            // Function set: 8 bits, 1 line, 5x8 dots
+            (PinState::Low, 0b00110000, b"        ", 0),
+            // Display and cursor on
+            (PinState::Low, 0b00001110, b"        ", 0),
+            // Entry mode: increment, no shift
+            (PinState::Low, 0b00000110, b"        ", 0),
+            (PinState::High, b'H', b"H       ", 1),
+            (PinState::High, b'I', b"HI      ", 2),
+            (PinState::High, b'T', b"HIT     ", 3),
+            (PinState::High, b'A', b"HITA    ", 4),
+            (PinState::High, b'C', b"HITAC   ", 5),
+            (PinState::High, b'H', b"HITACH  ", 6),
+            (PinState::High, b'I', b"HITACHI ", 7),
+            // Entry mode: increment and shift
+            (PinState::Low, 0b00000111, b"HITACHI ", 7),
+            (PinState::High, b' ', b"ITACHI  ", 7),
+            (PinState::High, b'M', b"TACHI M ", 7),
+            (PinState::High, b'I', b"ACHI MI ", 7),
+            (PinState::High, b'C', b"CHI MIC ", 7),
+            (PinState::High, b'R', b"HI MICR ", 7),
+            (PinState::High, b'O', b"I MICRO ", 7),
+            (PinState::High, b'K', b" MICROK ", 7),
+            (PinState::High, b'O', b"MICROKO ", 7),
+            // Cursor shift left, twice
+            (PinState::Low, 0b00010000, b"MICROKO ", 6),
+            (PinState::Low, 0b00010000, b"MICROKO ", 5),
+            // C over K: the display moves left
+            (PinState::High, b'C', b"ICROCO  ", 5),
+            // Display shift right: the cursor moves with the text
+            (PinState::Low, 0b00011100, b"MICROCO ", 6),
+            // Cursor shift right
+            (PinState::Low, 0b00010100, b"MICROCO ", 7),
+            (PinState::High, b'M', b"ICROCOM ", 7),
+            // Return home
+            (PinState::Low, 0b00000010, b"HITACHI ", 0),
+        ];
+
+        let mut simulator = Simulator::new();
+        for (step, &(rs, data, display, cursor)) in steps.iter().enumerate() {
+            send(&mut simulator, rs, data);
+            assert_eq!(&visible(&simulator, 8)[..], &display[..], "step {}", step);
+            assert_eq!(cursor_column(&simulator), Some(cursor), "step {}", step);
+        }
+    }
+
+    #[test]
+    fn geometry() {
+        assert_eq!(Geometry::G16x1Split.cell(0, 8), (1, 0));
+        assert_eq!(Geometry::G20x4.cell(2, 0), (0, 20));
+        assert_eq!(Geometry::G20x4.cell(3, 19), (1, 39));
+        assert_eq!(Geometry::G16x4.cell(2, 0), (0, 16));
+
+        let mut simulator = Simulator::new();
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x14);
+        send(&mut simulator, PinState::High, b'a');
+        // Shift the text left
+        send(&mut simulator, PinState::Low, 0b00011000);
+
+        let graphics = simulator.graphics.lock().unwrap();
+        let (line, column) = Geometry::G20x4.cell(2, 0);
+        assert_eq!(graphics.shown_dots(line, column, false), [0; 11]);
+        let (line, column) = Geometry::G20x4.cell(0, 19);
+        assert_eq!(graphics.shown_dots(line, column, false), graphics.dots(0, 0x14, false));
+    }
+
+    #[test]
+    fn dual_controller() {
+        let mut top = Simulator::new();
+        let mut bottom = top.share_bus();
+        send(&mut top, PinState::High, b'a');
+        send(&mut bottom, PinState::High, b'b');
+        send(&mut bottom, PinState::High, b'c');
+
+        assert_eq!(&top.graphics.lock().unwrap().ddram[..2], b"a ");
+        assert_eq!(&bottom.graphics.lock().unwrap().ddram[..2], b"bc");
+        assert_eq!(Geometry::G40x4.controller(2), 1);
+        assert_eq!(Geometry::G40x4.cell(3, 39), (1, 39));
+    }
+
+    #[test]
+    fn missing_controller() {
+        // A 40x4 layout on a single controller: rows 3 and 4 have nobody to show them
+        let mut simulator = Simulator::new().geometry(Geometry::G40x4);
+        send(&mut simulator, PinState::High, b'a');
+        let controllers = [simulator.graphics.clone()];
+
+        let top = graphics::row_dots(&controllers, Geometry::G40x4, 0, false);
+        assert_eq!(top[0], simulator.graphics.lock().unwrap().glyph(b'a'));
+        let bottom = graphics::row_dots(&controllers, Geometry::G40x4, 3, false);
+        assert_eq!(bottom.len(), 40);
+        assert!(bottom.iter().all(|dots| dots.iter().all(|&row| row == 0)));
+    }
+
+    #[test]
+    fn shared_bus() {
+        let first = Simulator::new();
+        let mut second = first.share_bus();
+        let mut third = second.share_bus();
+
+        send(&mut second, PinState::Low, 0b10000000 | 0x40);
+        send(&mut third, PinState::High, b'c');
+        // RS and data change without E on the first display
+        first.rs.set(PinState::High);
+        first.data.set(b'x');
+        assert_eq!(receive(&mut second, PinState::Low), 0x40);
+
+        assert_eq!(first.graphics.lock().unwrap().ddram[0], b' ');
+        assert_eq!(second.graphics.lock().unwrap().ddram[40], b' ');
+        assert_eq!(third.graphics.lock().unwrap().ddram[0], b'c');
+    }
+
+    #[test]
+    fn falling_edge() {
+        let mut simulator = Simulator::new();
+        let diagnostics = simulator.diagnostics();
+
+        simulator.rs.set(PinState::High);
+        simulator.data.set(b'a');
+        simulator.high();
+        // Late data is still latched...
+        simulator.data.set(b'b');
+        simulator.low();
+        wait(&simulator);
+        // ...but a late RS is not.
+        simulator.high();
+        simulator.rs.set(PinState::Low);
+        simulator.low();
+
+        assert_eq!(&simulator.graphics.lock().unwrap().ddram[..2], b"bb");
+        assert_eq!(
+            diagnostics.take(),
+            vec![
+                Diagnostic::DataChanged {
+                    rising: b'a',
+                    falling: b'b',
+                },
+                Diagnostic::ControlChanged,
+            ]
+        );
+        assert!(diagnostics.is_empty());
+
+        // Nothing is kept for a simulator nobody listens to
+        let mut simulator = Simulator::new();
+        simulator.high();
+        simulator.data.set(b'c');
+        simulator.low();
+        assert!(simulator.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn busy() {
+        let mut simulator = Simulator::new().busy_policy(BusyPolicy::Drop);
+        let diagnostics = simulator.diagnostics();
+
+        // Clear display, then write right away
+        simulator.rs.set(PinState::Low);
+        simulator.data.set(0b00000001);
+        simulator.high();
+        simulator.low();
+        simulator.rw.set(PinState::High);
+        simulator.high();
+        assert_eq!(simulator.data.get() & 0x80, 0x80);
+        simulator.low();
+        simulator.rs.set(PinState::High);
+        simulator.rw.set(PinState::Low);
+        simulator.data.set(b'a');
+        simulator.high();
+        simulator.low();
+
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[0], b' ');
+        match diagnostics.take()[..] {
+            [Diagnostic::Busy { rs: true, data: b'a', .. }] => (),
+            ref other => panic!("unexpected diagnostics: {:?}", other),
+        }
+
+        // Whatever the seed, a corrupted write never lands intact
+        for seed in 0..16 {
+            let mut simulator = Simulator::new().busy_policy(BusyPolicy::Corrupt { seed: seed });
+            for &(rs, data) in &[(PinState::Low, 0b00000001), (PinState::High, b'b')] {
+                simulator.rs.set(rs);
+                simulator.data.set(data);
+                simulator.high();
+                simulator.low();
+            }
+            assert_ne!(simulator.graphics.lock().unwrap().ddram[0], b'b');
+        }
+    }
+
+    #[test]
+    fn virtual_clock() {
+        use lcd_hd44780::Sleep;
+
+        let simulator = Simulator::new();
+        let mut sleep = super::Sleep {
+            clock: simulator.clock(),
+        };
+        sleep.sleep(3_000_000);
+        assert_eq!(simulator.clock().now(), Duration::from_secs(3));
+
+        // Polling the busy flag lets time pass
+        let mut simulator = simulator;
+        let diagnostics = simulator.diagnostics();
+        simulator.rs.set(PinState::Low);
+        simulator.data.set(0b00000001);
+        simulator.high();
+        simulator.low();
+        simulator.rw.set(PinState::High);
+        let mut polls = 0;
+        loop {
+            simulator.high();
+            let busy = simulator.data.get() & 0x80 != 0;
+            simulator.low();
+            if !busy {
+                break;
+            }
+            polls += 1;
+        }
+        assert!(polls > 1000);
+        assert!(simulator.clock().now() >= Duration::from_micros(3_001_520));
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn time_scale() {
+        let simulator = Simulator::new().time_scale(100.0);
+        let clock = simulator.clock();
+        let start = std::time::Instant::now();
+        clock.advance(Duration::from_millis(200));
+        assert!(start.elapsed() >= Duration::from_millis(2));
+        assert!(clock.now() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn power_on_reset() {
+        let mut simulator = Simulator::new().power_on(PowerOn::Reset);
+        assert_eq!(receive(&mut simulator, PinState::Low) & 0x80, 0x80);
+        wait(&simulator);
+        let graphics = simulator.graphics.lock().unwrap();
+        assert!(!graphics.display);
+        assert_eq!(graphics.lines().len(), 1);
+    }
+
+    #[test]
+    fn failed_reset_recovers() {
+        for seed in 0..32 {
+            let mut simulator = Simulator::new().power_on(PowerOn::FailedReset { seed: seed });
+            // Initialization by instruction, 4-bit interface
+            for &nibble in &[0x3, 0x3, 0x3, 0x2] {
+                send_nibble(&mut simulator, PinState::Low, nibble);
+            }
+            send_4bit(&mut simulator, PinState::Low, 0b00101000);
+            send_4bit(&mut simulator, PinState::Low, 0b00001100);
+            send_4bit(&mut simulator, PinState::Low, 0b00000001);
+            send_4bit(&mut simulator, PinState::Low, 0b00000110);
+            send_4bit(&mut simulator, PinState::High, b'a');
+
+            let graphics = simulator.graphics.lock().unwrap();
+            assert_eq!(&graphics.ddram[..2], b"a ", "seed {}", seed);
+        }
+
+        let first = Simulator::new().power_on(PowerOn::FailedReset { seed: 1 });
+        let second = Simulator::new().random_cgram(1);
+        assert_ne!(first.graphics.lock().unwrap().cgram[..], [0; 64][..]);
+        assert_ne!(second.graphics.lock().unwrap().cgram[..], [0; 64][..]);
+    }
+
+    #[test]
+    fn desync() {
+        let mut simulator = Simulator::new();
+        let desync = simulator.desync();
+        init_4bit(&mut simulator);
+
+        // MCU reset between two nibbles
+        desync.expect_low_nibble();
+        send_4bit(&mut simulator, PinState::High, 0x41);
+        send_4bit(&mut simulator, PinState::High, 0x42);
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[0], 0x04);
+
+        init_4bit(&mut simulator);
+        send_4bit(&mut simulator, PinState::High, b'a');
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[0], b'a');
+
+        // A missed pulse has the same effect
+        desync.drop_pulse();
+        send_4bit(&mut simulator, PinState::High, 0x41);
+        send_4bit(&mut simulator, PinState::High, 0x42);
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[1], 0x14);
+
+        // 8-bit transfers come whole
+        let mut simulator = Simulator::new();
+        simulator.desync().expect_low_nibble();
+        send(&mut simulator, PinState::High, b'a');
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[0], b'a');
+    }
+
+    #[test]
+    fn init_timing() {
+        let mut simulator = Simulator::new();
+        let diagnostics = simulator.diagnostics();
+
+        simulator.clock.advance(Duration::from_millis(15));
+        send(&mut simulator, PinState::Low, 0b00110000);
+        simulator.clock.advance(Duration::from_millis(5));
+        send(&mut simulator, PinState::Low, 0b00110000);
+        send(&mut simulator, PinState::Low, 0b00110000);
+        send(&mut simulator, PinState::Low, 0b00111000);
+
+        match diagnostics.take()[..] {
+            [Diagnostic::InitTooEarly { step: 1, gap: first, .. }, Diagnostic::InitTooEarly { step: 3, gap: third, .. }] =>
+            {
+                assert!(first >= Duration::from_millis(15) && first < Duration::from_millis(16));
+                assert!(third < Duration::from_micros(100));
+            }
+            ref other => panic!("unexpected diagnostics: {:?}", other),
+        }
+
+        // Done right, once anything else ended the sequence
+        send(&mut simulator, PinState::Low, 0b00000001);
+        simulator.clock.advance(Duration::from_millis(20));
+        for &delay in &[0, 4_100, 100] {
+            simulator.clock.advance(Duration::from_micros(delay));
+            send(&mut simulator, PinState::Low, 0b00110000);
+        }
+        assert!(diagnostics.is_empty());
+
+        // From 4-bit mode, the first two steps pair up in a single byte
+        init_4bit(&mut simulator);
+        simulator.clock.advance(Duration::from_millis(20));
+        diagnostics.take();
+        for &delay in &[0, 4_100, 100] {
+            simulator.clock.advance(Duration::from_micros(delay));
+            send_nibble(&mut simulator, PinState::Low, 0x3);
+        }
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn pin_timing() {
+        use timing::Parameter::*;
+
+        fn violations(diagnostics: &Diagnostics) -> Vec<Parameter> {
+            diagnostics
+                .take()
+                .into_iter()
+                .filter_map(|diagnostic| match diagnostic {
+                    Diagnostic::PinTiming { parameter, .. } => Some(parameter),
+                    // The second byte is also too early for the controller
+                    _ => None,
+                })
+                .collect()
+        }
+
+        fn write(simulator: &mut Simulator, rs: &mut Pin, data: &mut [BitPin; 8], byte: u8) {
+            rs.high();
+            drive(data, byte);
+            simulator.high();
+            simulator.low();
+        }
+
+        for &(supply, edge_time, ref expected) in &[
+            (Supply::V5, 250, vec![]),
+            (Supply::V3_3, 250, vec![EnablePulse]),
+            (
+                Supply::V5,
+                0,
+                vec![AddressSetup, EnablePulse, DataSetup, DataHold],
+            ),
+        ] {
+            let mut simulator = Simulator::new()
+                .supply(supply)
+                .edge_time(Duration::from_nanos(edge_time));
+            let diagnostics = simulator.diagnostics();
+            let mut rs = Pin::new(simulator.rs.clone(), simulator.control_changed.clone());
+            let mut data = BitPin::new_group(simulator.data_bus());
+
+            write(&mut simulator, &mut rs, &mut data, b'a');
+            // No time for hold: the next byte goes out right away
+            write(&mut simulator, &mut rs, &mut data, b'b');
+            let mut found = violations(&diagnostics);
+            found.sort_by_key(|parameter| *parameter as u8);
+            found.dedup();
+            assert_eq!(&found, expected, "{:?} {}ns", supply, edge_time);
+        }
+
+        // Hold is measured to the first change after E falls, not the last one
+        let mut simulator = Simulator::new().edge_time(Duration::from_nanos(0));
+        let diagnostics = simulator.diagnostics();
+        let mut rs = Pin::new(simulator.rs.clone(), simulator.control_changed.clone());
+        let mut data = BitPin::new_group(simulator.data_bus());
+        write(&mut simulator, &mut rs, &mut data, b'a');
+        diagnostics.take();
+        data[0].low();
+        simulator.clock.advance(Duration::from_millis(1));
+        data[1].high();
+        simulator.high();
+        assert!(violations(&diagnostics).contains(&DataHold));
+    }
+
+    #[test]
+    fn contention() {
+        let mut simulator = Simulator::new();
+        let diagnostics = simulator.diagnostics();
+        let mut data = BitPin::new_group(simulator.data_bus());
+
+        // The MCU forgot to release D0-D7 before reading the busy flag
+        receive(&mut simulator, PinState::Low);
+        assert_eq!(
+            diagnostics.take(),
+            vec![Diagnostic::Contention { lines: 0xFF }]
+        );
+
+        for pin in data.iter_mut() {
+            pin.release();
+        }
+        receive(&mut simulator, PinState::Low);
+        assert!(diagnostics.is_empty());
+
+        // Grabbing the bus while E is high counts too
+        simulator.rw.set(PinState::High);
+        simulator.high();
+        let mut pin = BitPin::new(simulator.data_bus(), 3);
+        simulator.low();
+        assert_eq!(
+            diagnostics.take(),
+            vec![Diagnostic::Contention { lines: 0b00001000 }]
+        );
+
+        // Even if it lets go before E falls
+        pin.release();
+        simulator.high();
+        BitPin::new(simulator.data_bus(), 5).release();
+        simulator.low();
+        assert_eq!(
+            diagnostics.take(),
+            vec![Diagnostic::Contention { lines: 0b00100000 }]
+        );
+    }
+
+    #[test]
+    fn tri_state() {
+        use gpio_traits::pin::Input;
+
+        fn sample(data: &[BitPin]) -> u8 {
+            data.iter()
+                .enumerate()
+                .filter(|&(_, pin)| pin::is_high(pin.read()))
+                .fold(0, |byte, (i, _)| byte | 1 << i)
+        }
+
+        let mut simulator = Simulator::new();
+        let mut data = BitPin::new_group(simulator.data_bus());
+        for pin in data.iter_mut() {
+            pin.set_mode(pin::Mode::Input);
+        }
+        assert_eq!(sample(&data), 0xFF);
+
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x05);
+        simulator.rs.set(PinState::Low);
+        simulator.rw.set(PinState::High);
+        simulator.high();
+        assert_eq!(sample(&data), 0x05);
+        simulator.low();
+        assert_eq!(sample(&data), 0xFF);
+
+        // Invalid DDRAM addresses leave the bus floating
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x30);
+        assert_eq!(receive(&mut simulator, PinState::High), 0xFF);
+
+        // Without pull-ups, in 4-bit mode
+        let mut simulator = Simulator::new().floating(0x00);
+        let mut data = BitPin::new_group(simulator.data_bus());
+        for pin in data.iter_mut() {
+            pin.set_mode(pin::Mode::Input);
+        }
+        send(&mut simulator, PinState::Low, 0b00101000);
+        send_4bit(&mut simulator, PinState::Low, 0b10000000 | 0x05);
+        // `send` bypasses the pins
+        simulator.data_bus().settle();
+        simulator.rw.set(PinState::High);
+        simulator.high();
+        assert_eq!(sample(&data), 0x00);
+        simulator.low();
+        simulator.high();
+        assert_eq!(sample(&data), 0x50);
+        simulator.low();
+
+        data[0].set_mode(pin::Mode::HighZ);
+        data[1].set_mode(pin::Mode::Output);
+        data[1].high();
+        assert_eq!(sample(&data), 0x02);
+    }
+
+    #[test]
+    fn read_releases_bus() {
+        let mut simulator = Simulator::new();
+        let _data = BitPin::new_group(simulator.data_bus());
+        send(&mut simulator, PinState::Low, 0b10000000 | 0x45);
+
+        // D0-D7 left as outputs, driving 0x00: the read is contended...
+        assert_eq!(receive(&mut simulator, PinState::Low), 0x45);
+        // ...but once the controller lets go, the MCU's levels are back
+        assert_eq!(simulator.data.get(), 0x00);
+        simulator.rs.set(PinState::High);
+        simulator.rw.set(PinState::Low);
+        simulator.high();
+        simulator.low();
+        assert_eq!(simulator.graphics.lock().unwrap().ddram[40 + 5], 0x00);
+    }
+
+    #[test]
+    fn faults() {
+        use fault::Fault;
+
+        fn run(faults: &Faults, text: &[u8]) -> Vec<u8> {
+            let simulator = Simulator::new();
+            let graphics = simulator.graphics.clone();
+            let clock = simulator.clock();
+            let mut data: Vec<_> = (0..8).map(|i| simulator.faulty_data(faults, i)).collect();
+            let (mut rs, _) = simulator.faulty_control(faults);
+            let mut enable = faults.wrap(Line::E, simulator);
+            rs.high();
+            for &byte in text {
+                drive(&mut data, byte);
+                enable.high();
+                enable.low();
+                clock.advance(Duration::from_micros(50));
+            }
+            let ddram = graphics.lock().unwrap().ddram;
+            ddram[..8].to_vec()
+        }
+
+        let text = b"abcd";
+        assert_eq!(run(&Faults::new(0), text), b"abcd    ");
+        // D5 shorted: lower case becomes upper case
+        let stuck = Faults::new(0).fault(Line::D(5), Fault::StuckLow);
+        assert_eq!(run(&stuck, text), b"ABCD    ");
+        // D0 and D1 crossed
+        assert_eq!(run(&Faults::new(0).swap(0, 1), text), b"bacd    ");
+        // Both edges of every E pulse bounce, so each byte lands three times
+        let glitch = Faults::new(0).fault(Line::E, Fault::Glitch(1.0));
+        assert_eq!(run(&glitch, text), b"aaabbbcc");
+        // Lost writes are reproducible
+        let open = Faults::new(7).fault(Line::D(0), Fault::Intermittent(0.5));
+        let text = b"abababab";
+        assert_eq!(run(&open, text), run(&open, text));
+        assert_ne!(run(&open, text), text);
+        // An open line floats high: b (0x62) reads as c (0x63)
+        let open = Faults::new(0).fault(Line::D(0), Fault::Intermittent(1.0));
+        assert_eq!(run(&open, b"bb"), b"cc      ");
+    }
+
+    #[test]
+    #[should_panic(expected = "no data line D8")]
+    fn faults_past_d7() {
+        Faults::new(0).swap(0, 8);
+    }
+
+    #[test]
+    fn wiring() {
+        fn init(wiring: &Wiring<&str>) -> Vec<Diagnostic> {
+            let simulator = Simulator::new().check_wiring();
+            let diagnostics = simulator.diagnostics();
+            let clock = simulator.clock();
+            let simulator = Rc::new(RefCell::new(simulator));
+            let pin = |id| Simulator::wired_pin(&simulator, wiring, &id);
+
+            // What the firmware believes are RS, E and D4-D7
+            let mut rs = pin("PB0");
+            let mut enable = pin("PB1");
+            let mut data: Vec<_> = ["PA4", "PA5", "PA6", "PA7"].iter().map(|&id| pin(id)).collect();
+            clock.advance(Duration::from_millis(50));
+            rs.low();
+            for &nibble in &[0x3, 0x3, 0x3, 0x2] {
+                drive(&mut data, nibble);
+                enable.high();
+                enable.low();
+                clock.advance(Duration::from_millis(5));
+            }
+            diagnostics.take()
+        }
+
+        let control = || {
+            Wiring::new()
+                .connect("PB0", Line::Rs)
+                .connect("PB1", Line::E)
+                .connect("PB2", Line::Backlight)
+        };
+
+        let good = control()
+            .connect("PA4", Line::D(4))
+            .connect("PA5", Line::D(5))
+            .connect("PA6", Line::D(6))
+            .connect("PA7", Line::D(7));
+        assert_eq!(init(&good), vec![]);
+
+        let low = control()
+            .connect("PA4", Line::D(0))
+            .connect("PA5", Line::D(1))
+            .connect("PA6", Line::D(2))
+            .connect("PA7", Line::D(3));
+        assert_eq!(
+            init(&low),
+            vec![Diagnostic::Miswired(wiring::Symptom::LowNibble)]
+        );
+
+        let reversed = control()
+            .connect("PA4", Line::D(7))
+            .connect("PA5", Line::D(6))
+            .connect("PA6", Line::D(5))
+            .connect("PA7", Line::D(4));
+        assert_eq!(
+            init(&reversed),
+            vec![Diagnostic::Miswired(wiring::Symptom::Reversed)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no data line D8")]
+    fn wiring_past_d7() {
+        Wiring::new().connect("PA0", Line::D(8));
+    }
+
+    #[test]
+    fn vcd() {
+        let mut simulator = Simulator::new();
+        let trace = simulator.trace();
+        trace.start();
+        let mut rs = Pin::new(simulator.rs.clone(), simulator.control_changed.clone());
+
+        simulator.clock.advance(Duration::from_micros(1));
+        rs.high();
+        simulator.data.set(b'a');
+        simulator.high();
+        simulator.low();
+
+        let mut vcd = Vec::new();
+        trace.write_vcd(&mut vcd).unwrap();
+        let vcd = String::from_utf8(vcd).unwrap();
+        let dump = &vcd[vcd.find("$enddefinitions $end\n").unwrap()..];
+        assert_eq!(
+            dump.lines().collect::<Vec<_>>(),
+            vec![
+                "$enddefinitions $end",
+                "#0",
+                "$dumpvars",
+                "0r",
+                "0w",
+                "0e0",
+                "b11111111 d",
+                "s- i",
+                "$end",
+                "#1000",
+                "1r",
+                "#1125",
+                "b01100001 d",
+                "1e0",
+                "#1375",
+                "0e0",
+                "swrite_data(0x61) i",
+            ]
+        );
+
+        // A second controller gets its own E wire, and the dump starts when the trace does
+        let mut second = simulator.share_bus();
+        trace.start();
+        second.high();
+        second.low();
+
+        let mut vcd = Vec::new();
+        trace.write_vcd(&mut vcd).unwrap();
+        let vcd = String::from_utf8(vcd).unwrap();
+        assert!(vcd.contains("$var wire 1 e0 E1 $end\n$var wire 1 e1 E2 $end\n"));
+        let dump = &vcd[vcd.find("$enddefinitions $end\n").unwrap()..];
+        assert_eq!(
+            dump.lines().take(5).collect::<Vec<_>>(),
+            vec!["$enddefinitions $end", "#1500", "$dumpvars", "1r", "0w"]
+        );
+        assert!(dump.contains("\n#1625\n1e1\n"));
+    }
 }
@@ -1,69 +1,303 @@
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::time::Duration;
 
 use gpio_traits::pin::PinState;
-use gpio_traits::pin::Output;
+use gpio_traits::pin::{Input, Output};
+
+use clock::Clock;
+use vcd::Trace;
+
+/// A pin of the display module, on the MCU side of the cable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Line {
+    Rs,
+    Rw,
+    E,
+    /// D0 to D7.
+    D(u8),
+    Backlight,
+    /// Contrast.
+    V0,
+}
+
+/// The first change on a `Stamp` since the cell was last emptied.
+pub type FirstChange = Rc<Cell<Option<Duration>>>;
+
+/// Records when a group of lines last changed, in simulated time.
+#[derive(Clone)]
+pub struct Stamp {
+    clock: Clock,
+    changed: Rc<Cell<Option<Duration>>>,
+    // One per controller, each emptied at its own falling edges
+    watchers: Rc<RefCell<Vec<FirstChange>>>,
+    trace: Option<Trace>,
+}
+
+impl Stamp {
+    pub fn new(clock: Clock) -> Self {
+        Stamp {
+            clock: clock,
+            changed: Rc::new(Cell::new(None)),
+            watchers: Rc::new(RefCell::new(Vec::new())),
+            trace: None,
+        }
+    }
+
+    /// Also shows each change to a trace.
+    pub fn traced(clock: Clock, trace: Trace) -> Self {
+        Stamp {
+            trace: Some(trace),
+            ..Stamp::new(clock)
+        }
+    }
+
+    /// Shows the trace a change that doesn't count as one from the MCU.
+    pub fn sample(&self) {
+        if let Some(ref trace) = self.trace {
+            trace.sample();
+        }
+    }
+
+    pub fn touch(&self) {
+        let now = self.clock.now();
+        self.changed.set(Some(now));
+        for first in self.watchers.borrow().iter() {
+            if first.get().is_none() {
+                first.set(Some(now));
+            }
+        }
+        self.sample();
+    }
+
+    /// Returns the time of the last change, if any.
+    pub fn get(&self) -> Option<Duration> {
+        self.changed.get()
+    }
+
+    /// Returns a cell that the next change fills in, whenever it is empty.
+    ///
+    /// Emptying it at some instant gives the first change since then.
+    pub fn watch(&self) -> FirstChange {
+        let first = Rc::new(Cell::new(None));
+        self.watchers.borrow_mut().push(first.clone());
+        first
+    }
+}
+
+/// D0-D7, as seen from the MCU.
+#[derive(Clone)]
+pub struct DataBus {
+    /// Level of each line.
+    pub byte: Rc<Cell<u8>>,
+    /// Levels the MCU outputs, whether it drives the lines or not.
+    pub levels: Rc<Cell<u8>>,
+    /// Lines the MCU drives, one bit each.
+    pub driven: Rc<Cell<u8>>,
+    /// Lines the controller drives.
+    pub controller: Rc<Cell<u8>>,
+    /// Level of the lines nobody drives.
+    pub floating: Rc<Cell<u8>>,
+    /// Lines both sides drove since the controller took the bus.
+    pub contention: Rc<Cell<u8>>,
+    pub stamp: Stamp,
+}
+
+impl DataBus {
+    /// Works out the level of each line from whoever drives it.
+    ///
+    /// The controller keeps what it put on its lines, the MCU's lines
+    /// follow its output levels, and the others float.
+    pub fn settle(&self) {
+        let driven = self.driven.get();
+        let controller = self.controller.get();
+        let free = !(driven | controller);
+        self.contention.set(self.contention.get() | (driven & controller));
+        let byte = (self.byte.get() & controller)
+            | (self.levels.get() & driven & !controller)
+            | (self.floating.get() & free);
+        self.byte.set(byte);
+        self.stamp.sample();
+    }
+}
+
+/// Direction of a data pin on the MCU side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Drives the line with the last level written.
+    Output,
+    /// Reads the line without driving it.
+    Input,
+    /// Disconnected from the line: reads return low.
+    HighZ,
+}
 
 pub struct BitPin {
-    byte: Rc<Cell<u8>>,
+    bus: DataBus,
     offset: u8,
+    mode: Mode,
+    // Cut off from the line, as by a cracked joint
+    open: bool,
 }
 
 pub fn new_state() -> Rc<Cell<PinState>> {
     Rc::new(Cell::new(PinState::Low))
 }
 
+pub fn is_high(state: PinState) -> bool {
+    match state {
+        PinState::Low => false,
+        PinState::High => true,
+    }
+}
+
 
 impl BitPin {
-    pub fn new(byte: Rc<Cell<u8>>, offset: u8) -> Self {
-        BitPin {
-            byte: byte,
+    /// Creates an output pin, driving the line with its current output level.
+    pub fn new(bus: DataBus, offset: u8) -> Self {
+        let mut pin = BitPin {
+            bus: bus,
             offset: offset,
-        }
+            mode: Mode::HighZ,
+            open: false,
+        };
+        pin.set_mode(Mode::Output);
+        pin
+    }
+
+    pub fn new_group(bus: DataBus) -> [BitPin; 8] {
+        [
+            BitPin::new(bus.clone(), 0),
+            BitPin::new(bus.clone(), 1),
+            BitPin::new(bus.clone(), 2),
+            BitPin::new(bus.clone(), 3),
+            BitPin::new(bus.clone(), 4),
+            BitPin::new(bus.clone(), 5),
+            BitPin::new(bus.clone(), 6),
+            BitPin::new(bus.clone(), 7),
+        ]
     }
 
-    pub fn new_group(byte: Rc<Cell<u8>>) -> [BitPin; 8] {
+    /// Returns only D4-D7, for 4-bit wiring.
+    pub fn new_nibble(bus: DataBus) -> [BitPin; 4] {
         [
-            BitPin::new(byte.clone(), 0),
-            BitPin::new(byte.clone(), 1),
-            BitPin::new(byte.clone(), 2),
-            BitPin::new(byte.clone(), 3),
-            BitPin::new(byte.clone(), 4),
-            BitPin::new(byte.clone(), 5),
-            BitPin::new(byte.clone(), 6),
-            BitPin::new(byte.clone(), 7),
+            BitPin::new(bus.clone(), 4),
+            BitPin::new(bus.clone(), 5),
+            BitPin::new(bus.clone(), 6),
+            BitPin::new(bus.clone(), 7),
         ]
     }
 }
 
+impl BitPin {
+    /// Stops driving the line, as when switching it to an input.
+    ///
+    /// Later writes only change the level it would output.
+    pub fn release(&mut self) {
+        self.set_mode(Mode::Input);
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Switches the pin direction.
+    ///
+    /// Switching back to output drives the last level written.
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+        self.connect();
+    }
+
+    /// Cuts the pin off from the line, or connects it back.
+    ///
+    /// While open, the line floats whatever the mode.
+    pub fn set_open(&mut self, open: bool) {
+        self.open = open;
+        self.connect();
+    }
+
+    fn connect(&mut self) {
+        let driven = self.bus.driven.get();
+        if self.mode == Mode::Output && !self.open {
+            self.bus.driven.set(driven | 1 << self.offset);
+            self.drive();
+        } else {
+            self.bus.driven.set(driven & !(1 << self.offset));
+            self.bus.settle();
+        }
+    }
+
+    fn drive(&mut self) {
+        let old = self.bus.byte.get();
+        self.bus.settle();
+        if self.bus.byte.get() != old {
+            self.bus.stamp.touch();
+        }
+    }
+
+    fn set(&mut self, level: bool) {
+        let levels = self.bus.levels.get();
+        self.bus.levels.set(if level {
+            levels | (1 << self.offset)
+        } else {
+            levels & !(1 << self.offset)
+        });
+        if self.mode == Mode::Output && !self.open {
+            self.drive();
+        }
+    }
+}
+
 impl Output for BitPin {
     fn high(&mut self) {
-        let byte = self.byte.get();
-        self.byte.set(byte | (1 << self.offset));
+        self.set(true);
     }
 
     fn low(&mut self) {
-        let byte = self.byte.get();
-        self.byte.set(byte & !(1 << self.offset));
+        self.set(false);
+    }
+}
+
+/// Reads what is on the line: the MCU's own level in output mode,
+/// the controller's during a read, or the floating level.
+impl Input for BitPin {
+    fn read(&self) -> PinState {
+        if self.mode != Mode::HighZ && (self.bus.byte.get() & (1 << self.offset)) != 0 {
+            PinState::High
+        } else {
+            PinState::Low
+        }
     }
 }
 
 pub struct Pin {
     state: Rc<Cell<PinState>>,
+    stamp: Stamp,
 }
 
 impl Pin {
-    pub fn new(state: Rc<Cell<PinState>>) -> Self {
-        Pin { state: state }
+    pub fn new(state: Rc<Cell<PinState>>, stamp: Stamp) -> Self {
+        Pin {
+            state: state,
+            stamp: stamp,
+        }
+    }
+
+    fn set(&mut self, state: PinState) {
+        if is_high(state) != is_high(self.state.get()) {
+            self.state.set(state);
+            self.stamp.touch();
+        }
     }
 }
 
 impl Output for Pin {
     fn high(&mut self) {
-        self.state.set(PinState::High);
+        self.set(PinState::High);
     }
 
     fn low(&mut self) {
-        self.state.set(PinState::Low);
+        self.set(PinState::Low);
     }
 }
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+/// Execution time of most instructions, with fosc = 270kHz.
+pub const INSTRUCTION_US: u64 = 37;
+/// Execution time of "clear display" and "return home".
+pub const CLEAR_US: u64 = 1520;
+/// Extra time to update the address counter after a RAM access.
+pub const ADDRESS_UPDATE_US: u64 = 4;
+/// Busy period of the internal reset, after Vcc rises.
+pub const RESET_US: u64 = 10_000;
+/// Initialization by instruction: wait after Vcc rises to 4.5V...
+pub const POWER_ON_US: u64 = 40_000;
+/// ...after the first function set...
+pub const INIT_FIRST_US: u64 = 4_100;
+/// ...and after the second.
+pub const INIT_SECOND_US: u64 = 100;
+/// Shortest enable cycle (tcycE), at 5V.
+pub const ENABLE_CYCLE_NS: u64 = 500;
+
+/// Supply voltage, which selects the bus timing requirements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Supply {
+    /// 4.5V to 5.5V
+    V5,
+    /// 2.7V to 4.5V
+    V3_3,
+}
+
+/// A bus timing requirement of the write and read cycles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parameter {
+    /// RS and RW before E rises (tAS).
+    AddressSetup,
+    /// RS and RW after E falls (tAH).
+    AddressHold,
+    /// E high (PWEH).
+    EnablePulse,
+    /// D0-D7 before E falls (tDSW).
+    DataSetup,
+    /// D0-D7 after E falls (tH).
+    DataHold,
+}
+
+impl Parameter {
+    /// Returns the datasheet symbol.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Parameter::AddressSetup => "tAS",
+            Parameter::AddressHold => "tAH",
+            Parameter::EnablePulse => "PWEH",
+            Parameter::DataSetup => "tDSW",
+            Parameter::DataHold => "tH",
+        }
+    }
+}
+
+impl Supply {
+    /// Returns the minimum time for the given parameter.
+    pub fn minimum(self, parameter: Parameter) -> Duration {
+        let ns = match (self, parameter) {
+            (Supply::V5, Parameter::AddressSetup) => 40,
+            (Supply::V5, Parameter::AddressHold) => 10,
+            (Supply::V5, Parameter::EnablePulse) => 230,
+            (Supply::V5, Parameter::DataSetup) => 80,
+            (Supply::V5, Parameter::DataHold) => 10,
+            (Supply::V3_3, Parameter::AddressSetup) => 60,
+            (Supply::V3_3, Parameter::AddressHold) => 20,
+            (Supply::V3_3, Parameter::EnablePulse) => 450,
+            (Supply::V3_3, Parameter::DataSetup) => 195,
+            (Supply::V3_3, Parameter::DataHold) => 10,
+        };
+        Duration::from_nanos(ns)
+    }
+}
+
+/// What to do with instructions or data arriving while the controller is busy.
+///
+/// All of them report a `Diagnostic::Busy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BusyPolicy {
+    /// Execute it anyway.
+    Report,
+    /// Ignore it.
+    Drop,
+    /// Execute it with random bits flipped, at least one.
+    ///
+    /// The flips are seeded, so a run can be replayed.
+    Corrupt { seed: u64 },
+}
+
+/// Returns how long the controller stays busy after a transfer.
+pub fn execution_time(rs: bool, data: u8) -> Duration {
+    let us = match (rs, data) {
+        (true, _) => INSTRUCTION_US + ADDRESS_UPDATE_US,
+        (false, 0x01...0x03) => CLEAR_US,
+        (false, _) => INSTRUCTION_US,
+    };
+    Duration::from_micros(us)
+}
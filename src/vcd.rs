@@ -0,0 +1,219 @@
+use std::cell::{Cell, RefCell};
+use std::io;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpio_traits::pin::PinState;
+
+use clock::{nanos, Clock};
+use pin;
+
+/// Records the bus, to write it as a Value Change Dump.
+///
+/// On a shared bus, each controller has its own E wire.
+#[derive(Clone)]
+pub struct Trace {
+    clock: Clock,
+    rs: Rc<Cell<PinState>>,
+    rw: Rc<Cell<PinState>>,
+    data: Rc<Cell<u8>>,
+    // Number of enable lines
+    enables: Rc<Cell<usize>>,
+    recording: Rc<RefCell<Option<Recording>>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Levels {
+    rs: bool,
+    rw: bool,
+    data: u8,
+}
+
+struct Recording {
+    started: Duration,
+    initial: Levels,
+    last: Levels,
+    changes: Vec<(Duration, Change)>,
+}
+
+enum Change {
+    Rs(bool),
+    Rw(bool),
+    Enable(usize, bool),
+    Data(u8),
+    Instruction(String),
+}
+
+impl Trace {
+    pub fn new(
+        clock: Clock,
+        rs: Rc<Cell<PinState>>,
+        rw: Rc<Cell<PinState>>,
+        data: Rc<Cell<u8>>,
+    ) -> Self {
+        Trace {
+            clock: clock,
+            rs: rs,
+            rw: rw,
+            data: data,
+            enables: Rc::new(Cell::new(1)),
+            recording: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Adds the enable line of another controller, returning its index.
+    pub fn add_enable(&self) -> usize {
+        let index = self.enables.get();
+        self.enables.set(index + 1);
+        index
+    }
+
+    /// Starts recording, dropping anything recorded so far.
+    pub fn start(&self) {
+        let levels = Levels {
+            rs: pin::is_high(self.rs.get()),
+            rw: pin::is_high(self.rw.get()),
+            data: self.data.get(),
+        };
+        *self.recording.borrow_mut() = Some(Recording {
+            started: self.clock.now(),
+            initial: levels,
+            last: levels,
+            changes: Vec::new(),
+        });
+    }
+
+    /// Looks for changes on RS, RW and D0-D7.
+    pub fn sample(&self) {
+        let mut recording = self.recording.borrow_mut();
+        let recording = match *recording {
+            Some(ref mut recording) => recording,
+            None => return,
+        };
+        let now = self.clock.now();
+        let rs = pin::is_high(self.rs.get());
+        let rw = pin::is_high(self.rw.get());
+        let data = self.data.get();
+        if rs != recording.last.rs {
+            recording.changes.push((now, Change::Rs(rs)));
+        }
+        if rw != recording.last.rw {
+            recording.changes.push((now, Change::Rw(rw)));
+        }
+        if data != recording.last.data {
+            recording.changes.push((now, Change::Data(data)));
+        }
+        recording.last.rs = rs;
+        recording.last.rw = rw;
+        recording.last.data = data;
+    }
+
+    /// Records an edge on an enable line, after anything that changed before it.
+    pub fn enable(&self, line: usize, high: bool) {
+        self.sample();
+        if let Some(ref mut recording) = *self.recording.borrow_mut() {
+            recording
+                .changes
+                .push((self.clock.now(), Change::Enable(line, high)));
+        }
+    }
+
+    /// Records a decoded instruction, or data transfer.
+    pub fn instruction(&self, rs: bool, read: bool, data: u8) {
+        if let Some(ref mut recording) = *self.recording.borrow_mut() {
+            let text = decode(rs, read, data);
+            recording
+                .changes
+                .push((self.clock.now(), Change::Instruction(text)));
+        }
+    }
+
+    /// Writes what was recorded since `start`, with nanosecond timestamps.
+    pub fn write_vcd<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        let recording = self.recording.borrow();
+        let recording = match *recording {
+            Some(ref recording) => recording,
+            None => return Err(io::Error::other("trace not started")),
+        };
+
+        writeln!(out, "$version hd44780_simulator $end")?;
+        writeln!(out, "$timescale 1ns $end")?;
+        writeln!(out, "$scope module lcd $end")?;
+        writeln!(out, "$var wire 1 r RS $end")?;
+        writeln!(out, "$var wire 1 w RW $end")?;
+        let enables = self.enables.get();
+        for line in 0..enables {
+            if enables == 1 {
+                writeln!(out, "$var wire 1 e0 E $end")?;
+            } else {
+                writeln!(out, "$var wire 1 e{} E{} $end", line, line + 1)?;
+            }
+        }
+        writeln!(out, "$var wire 8 d D [7:0] $end")?;
+        writeln!(out, "$var string 1 i instruction $end")?;
+        writeln!(out, "$upscope $end")?;
+        writeln!(out, "$enddefinitions $end")?;
+
+        // E is low between pulses
+        let initial = recording.initial;
+        let started = nanos(recording.started);
+        writeln!(out, "#{}", started)?;
+        writeln!(out, "$dumpvars")?;
+        writeln!(out, "{}r", initial.rs as u8)?;
+        writeln!(out, "{}w", initial.rw as u8)?;
+        for line in 0..enables {
+            writeln!(out, "0e{}", line)?;
+        }
+        writeln!(out, "b{:08b} d", initial.data)?;
+        writeln!(out, "s- i")?;
+        writeln!(out, "$end")?;
+
+        let mut time = Some(started);
+        for &(at, ref change) in &recording.changes {
+            let at = nanos(at);
+            if time != Some(at) {
+                writeln!(out, "#{}", at)?;
+                time = Some(at);
+            }
+            match *change {
+                Change::Rs(high) => writeln!(out, "{}r", high as u8)?,
+                Change::Rw(high) => writeln!(out, "{}w", high as u8)?,
+                Change::Enable(line, high) => writeln!(out, "{}e{}", high as u8, line)?,
+                Change::Data(data) => writeln!(out, "b{:08b} d", data)?,
+                Change::Instruction(ref text) => writeln!(out, "s{} i", text)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Names a transfer, without spaces so it fits in a VCD string.
+fn decode(rs: bool, read: bool, data: u8) -> String {
+    let bit = |n: u8| (data >> n) & 1;
+    match (rs, read) {
+        (true, false) => format!("write_data(0x{:02x})", data),
+        (true, true) => format!("read_data(0x{:02x})", data),
+        (false, true) => format!("read_busy_flag(BF={},AC=0x{:02x})", bit(7), data & 0x7F),
+        (false, false) => match data {
+            0b00000000 => "nop".to_string(),
+            0b00000001 => "clear_display".to_string(),
+            0b00000010...0b00000011 => "return_home".to_string(),
+            0b00000100...0b00000111 => format!("entry_mode_set(I/D={},S={})", bit(1), bit(0)),
+            0b00001000...0b00001111 => format!(
+                "display_control(D={},C={},B={})",
+                bit(2),
+                bit(1),
+                bit(0)
+            ),
+            0b00010000...0b00011111 => format!("shift(S/C={},R/L={})", bit(3), bit(2)),
+            0b00100000...0b00111111 => format!(
+                "function_set(DL={},N={},F={})",
+                bit(4),
+                bit(3),
+                bit(2)
+            ),
+            0b01000000...0b01111111 => format!("set_cgram_address(0x{:02x})", data & 0x3F),
+            _ => format!("set_ddram_address(0x{:02x})", data & 0x7F),
+        },
+    }
+}
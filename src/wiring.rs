@@ -0,0 +1,156 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gpio_traits::pin::{Input, Output, PinState};
+
+use pin::{BitPin, Line, Mode, Pin};
+use Simulator;
+
+/// Which MCU pin goes to which pin of the display module.
+///
+/// MCU pins can be named with anything comparable: numbers, strings...
+#[derive(Clone, Debug)]
+pub struct Wiring<Id> {
+    connections: Vec<(Id, Line)>,
+}
+
+impl<Id: PartialEq> Default for Wiring<Id> {
+    fn default() -> Self {
+        Wiring {
+            connections: Vec::new(),
+        }
+    }
+}
+
+impl<Id: PartialEq> Wiring<Id> {
+    pub fn new() -> Self {
+        Wiring::default()
+    }
+
+    pub fn connect(mut self, mcu: Id, lcd: Line) -> Self {
+        if let Line::D(i) = lcd {
+            assert!(i < 8, "no data line D{}", i);
+        }
+        self.connections.push((mcu, lcd));
+        self
+    }
+
+    /// Returns the display pin an MCU pin goes to, if any.
+    pub fn line(&self, mcu: &Id) -> Option<Line> {
+        self.connections
+            .iter()
+            .find(|(id, _)| id == mcu)
+            .map(|&(_, line)| line)
+    }
+}
+
+/// An MCU pin, connected according to a `Wiring`.
+pub enum WiredPin {
+    /// RS, RW, or a line the controller doesn't see (backlight, V0).
+    Control(Pin),
+    Data(BitPin),
+    Enable(Rc<RefCell<Simulator>>),
+    /// Goes nowhere.
+    Unconnected,
+}
+
+impl WiredPin {
+    /// Switches the direction of a data pin; the others ignore it.
+    pub fn set_mode(&mut self, mode: Mode) {
+        if let WiredPin::Data(ref mut pin) = *self {
+            pin.set_mode(mode);
+        }
+    }
+}
+
+impl Output for WiredPin {
+    fn high(&mut self) {
+        match *self {
+            WiredPin::Control(ref mut pin) => pin.high(),
+            WiredPin::Data(ref mut pin) => pin.high(),
+            WiredPin::Enable(ref simulator) => simulator.borrow_mut().high(),
+            WiredPin::Unconnected => (),
+        }
+    }
+
+    fn low(&mut self) {
+        match *self {
+            WiredPin::Control(ref mut pin) => pin.low(),
+            WiredPin::Data(ref mut pin) => pin.low(),
+            WiredPin::Enable(ref simulator) => simulator.borrow_mut().low(),
+            WiredPin::Unconnected => (),
+        }
+    }
+}
+
+/// Only data lines can be read back; the others read low.
+impl Input for WiredPin {
+    fn read(&self) -> PinState {
+        match *self {
+            WiredPin::Data(ref pin) => pin.read(),
+            _ => PinState::Low,
+        }
+    }
+}
+
+/// A sign of a common wiring mistake.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symptom {
+    /// 4-bit traffic arrives on D0-D3 instead of D4-D7.
+    LowNibble,
+    /// The data lines are connected in reverse order.
+    Reversed,
+}
+
+impl Symptom {
+    pub fn description(self) -> &'static str {
+        match self {
+            Symptom::LowNibble => "4-bit data arrives on D0-D3 instead of D4-D7",
+            Symptom::Reversed => "the data lines look reversed",
+        }
+    }
+}
+
+/// Looks for wiring mistakes in the traffic.
+///
+/// The first three instructions should be the function sets of the
+/// initialization sequence, with 0x3 on D4-D7: anything else is suspect.
+#[derive(Clone, Debug, Default)]
+pub struct Check {
+    instructions: Vec<u8>,
+    // Data lines the MCU drove so far
+    driven: u8,
+    reported: Vec<Symptom>,
+}
+
+impl Check {
+    /// Looks at a write, returning the symptoms it reveals for the first time.
+    pub fn observe(&mut self, rs: bool, data: u8, driven: u8) -> Vec<Symptom> {
+        self.driven |= driven;
+        if !rs && self.instructions.len() < 3 {
+            self.instructions.push(data);
+        }
+
+        let mut symptoms = Vec::new();
+        if self.init(|data| data >> 4 != 0x3 && data & 0x0F == 0x3) {
+            symptoms.push(Symptom::LowNibble);
+        }
+        // 0x3 mirrored, on either half of the bus
+        if self.init(|data| data >> 4 == 0xC || data == 0x0C) {
+            symptoms.push(Symptom::Reversed);
+        }
+        if self.driven != 0 && self.driven & 0xF0 == 0 {
+            symptoms.push(Symptom::LowNibble);
+        }
+
+        symptoms.retain(|symptom| !self.reported.contains(symptom));
+        symptoms.dedup();
+        self.reported.extend(symptoms.iter().cloned());
+        symptoms
+    }
+
+    /// Tells if the three initialization steps are all alike.
+    fn init<F: Fn(u8) -> bool>(&self, f: F) -> bool {
+        self.instructions.len() == 3 && self.instructions.iter().all(|&data| f(data))
+    }
+}